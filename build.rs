@@ -0,0 +1,53 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    path::Path,
+    process::Command,
+};
+
+fn git(args: &[&str]) -> Option<Vec<u8>> {
+    let output = Command::new("git").args(args).output().ok()?;
+    output.status.success().then_some(output.stdout)
+}
+
+fn git_text(args: &[&str]) -> Option<String> {
+    git(args).map(|out| String::from_utf8_lossy(&out).trim().to_string())
+}
+
+/// Builds again when the sources change or another commit is checked out.
+fn rerun_if_changed() {
+    println!("cargo:rerun-if-changed=src");
+
+    let mut files = vec!["HEAD".to_string()];
+    // The branch which is checked out, unless the head is detached.
+    files.extend(git_text(&["symbolic-ref", "-q", "HEAD"]));
+    files.push("packed-refs".to_string());
+
+    for file in files {
+        // Missing files would make cargo run the build script every time.
+        match git_text(&["rev-parse", "--git-path", &file]) {
+            Some(path) if Path::new(&path).exists() => {
+                println!("cargo:rerun-if-changed={}", path)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Passes the commit the crate is built from to the answer cache as
+/// `AOC2021_COMMIT`, with a hash of the uncommitted changes if there are any.
+fn main() {
+    rerun_if_changed();
+
+    let Some(mut commit) = git_text(&["rev-parse", "--short", "HEAD"]) else {
+        return;
+    };
+
+    if let Some(diff) = git(&["diff", "HEAD"]).filter(|d| !d.is_empty()) {
+        let mut hasher = DefaultHasher::new();
+        diff.hash(&mut hasher);
+        commit = format!("{}-dirty.{:016x}", commit, hasher.finish());
+    }
+
+    println!("cargo:rustc-env=AOC2021_COMMIT={}", commit);
+}
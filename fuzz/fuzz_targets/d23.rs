@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc2021::{solvers::Day23, Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(text) = std::str::from_utf8(data) {
+        let _ = Day23::parse(text);
+    }
+});
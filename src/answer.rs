@@ -0,0 +1,97 @@
+use std::{convert::Infallible, fmt};
+
+use num::{BigInt, BigUint};
+use serde::{Deserialize, Serialize};
+
+/// Answer to a puzzle.
+///
+/// Serialized as `{"type": "integer", "value": 7}`, a grid as a list of rows and a
+/// big integer as a string of its decimal digits, which JSON readers can not
+/// round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Answer {
+    Integer(i64),
+    /// Integer which does not fit into an `i64`.
+    #[serde(with = "decimal")]
+    BigInteger(BigInt),
+    Text(String),
+    /// Picture drawn by the puzzle, one string per row.
+    Grid(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Integer(n),
+                        Err(_) => Answer::BigInteger(BigInt::from(n)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(i32, u32, i64, u64, i128, isize, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        match i64::try_from(&n) {
+            Ok(n) => Answer::Integer(n),
+            Err(_) => Answer::BigInteger(n),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Self {
+        Answer::from(BigInt::from(n))
+    }
+}
+
+/// For puzzles which have no answer at all.
+impl From<Infallible> for Answer {
+    fn from(never: Infallible) -> Self {
+        match never {}
+    }
+}
+
+mod decimal {
+    use num::BigInt;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        n: &BigInt,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(n)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<BigInt, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,251 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Context};
+use owo_colors::OwoColorize;
+use serde::Deserialize;
+
+use crate::{solve, Answer, Day, Puzzle};
+
+pub const DEFAULT_PATH: &str = "res/answers.toml";
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawValue {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DetailedAnswer {
+    answer: RawValue,
+    #[serde(default)]
+    slow: bool,
+}
+
+/// An answer as a plain value or as a table with details.
+///
+/// Tables are told apart from values before they are deserialized, so a typo in
+/// a table is reported as an unknown field and not as a mismatch of the variants.
+#[derive(Deserialize)]
+#[serde(try_from = "toml::Value")]
+enum RawAnswer {
+    Plain(RawValue),
+    Detailed(DetailedAnswer),
+}
+
+impl TryFrom<toml::Value> for RawAnswer {
+    type Error = toml::de::Error;
+
+    fn try_from(value: toml::Value) -> Result<Self, Self::Error> {
+        match value {
+            toml::Value::Table(_) => value.try_into().map(RawAnswer::Detailed),
+            _ => value.try_into().map(RawAnswer::Plain),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawInput {
+    first: Option<RawAnswer>,
+    second: Option<RawAnswer>,
+}
+
+/// Identifies an answer by day, input name and puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AnswerKey {
+    pub day: Day,
+    pub input: String,
+    pub puzzle: Puzzle,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub answer: String,
+    /// Takes too long to be checked by default.
+    pub slow: bool,
+}
+
+/// Manifest of expected answers, by default read from [`DEFAULT_PATH`].
+///
+/// It is keyed by day, input name (`res/dNN_<input>.txt`) and puzzle:
+/// ```toml
+/// [d01.small]
+/// first = 7
+/// second = { answer = 5, slow = true }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    answers: BTreeMap<AnswerKey, Expected>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Can not read '{}'", path.display()))?;
+        Self::from_toml(&text)
+            .with_context(|| format!("Invalid answer file '{}'", path.display()))
+    }
+
+    pub fn from_toml(text: &str) -> anyhow::Result<Self> {
+        let raw: BTreeMap<String, BTreeMap<String, RawInput>> =
+            toml::from_str(text)?;
+
+        let mut answers = BTreeMap::new();
+
+        for (day_name, inputs) in raw {
+            let day = day_name
+                .strip_prefix('d')
+                .ok_or(anyhow!("Invalid day key '{}', expected 'dNN'", day_name))?
+                .parse::<Day>()
+                .map_err(|e| anyhow!("Invalid day key '{}': {}", day_name, e))?;
+
+            for (input, raw) in inputs {
+                for (puzzle, answer) in
+                    [(Puzzle::First, raw.first), (Puzzle::Second, raw.second)]
+                {
+                    let (value, slow) = match answer {
+                        Some(RawAnswer::Plain(value)) => (value, false),
+                        Some(RawAnswer::Detailed(DetailedAnswer {
+                            answer,
+                            slow,
+                        })) => (answer, slow),
+                        None => continue,
+                    };
+
+                    let answer = match value {
+                        RawValue::Number(n) => n.to_string(),
+                        RawValue::Text(t) => t,
+                    };
+
+                    let key = AnswerKey {
+                        day,
+                        input: input.clone(),
+                        puzzle,
+                    };
+                    answers.insert(key, Expected { answer, slow });
+                }
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: Day, input: &str, puzzle: Puzzle) -> Option<&Expected> {
+        let key = AnswerKey {
+            day,
+            input: input.to_string(),
+            puzzle,
+        };
+        self.answers.get(&key)
+    }
+
+    /// All answers, ordered by day, input name and puzzle.
+    pub fn iter(&self) -> impl Iterator<Item = (&AnswerKey, &Expected)> + '_ {
+        self.answers.iter()
+    }
+}
+
+/// Outcome of checking a single answer.
+pub struct Check {
+    pub key: AnswerKey,
+    pub expected: Expected,
+    pub actual: anyhow::Result<Answer>,
+    pub time: Duration,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!(&self.actual, Ok(actual) if actual.to_string() == self.expected.answer)
+    }
+}
+
+/// Solves the puzzle of `key` on the input at `path` and compares the result.
+pub fn check(path: &Path, key: AnswerKey, expected: Expected) -> Check {
+    let start = Instant::now();
+    let actual = std::fs::read_to_string(path)
+        .with_context(|| format!("Can not read '{}'", path.display()))
+        .and_then(|text| solve(key.day, key.puzzle, &text));
+
+    Check {
+        key,
+        expected,
+        actual,
+        time: start.elapsed(),
+    }
+}
+
+/// Prints a PASS or FAIL line for every check, with a diff for wrong answers.
+pub fn print_checks(checks: &[Check], mut out: impl Write) -> io::Result<()> {
+    for check in checks {
+        let status = if check.passed() {
+            "PASS".green().to_string()
+        } else {
+            "FAIL".red().to_string()
+        };
+
+        writeln!(
+            out,
+            "{}  day {:>2}  {:<12}  {:<6}  {:.2?}",
+            status,
+            check.key.day.0,
+            check.key.input,
+            check.key.puzzle.to_string(),
+            check.time
+        )?;
+
+        match &check.actual {
+            Ok(actual) if !check.passed() => {
+                write_diff(&check.expected.answer, &actual.to_string(), &mut out)?
+            }
+            Ok(_) => {}
+            Err(e) => writeln!(out, "  error: {:#}", e)?,
+        }
+    }
+
+    let failed = checks.iter().filter(|c| !c.passed()).count();
+    writeln!(out, "\n{} checked, {} failed", checks.len(), failed)?;
+
+    Ok(())
+}
+
+/// Splits an input file name like `d07_small.txt` into its day and input name.
+pub fn parse_input_name(path: &Path) -> Option<(Day, String)> {
+    let stem = path.file_stem()?.to_str()?;
+    let (day, name) = stem.split_once('_')?;
+    let day = day.strip_prefix('d')?.parse().ok()?;
+
+    Some((day, name.to_string()))
+}
+
+/// Writes a line by line diff of an expected and actual answer.
+pub fn write_diff(
+    expected: &str,
+    actual: &str,
+    mut out: impl Write,
+) -> io::Result<()> {
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => writeln!(out, "  {}", e)?,
+            (e, a) => {
+                if let Some(e) = e {
+                    writeln!(out, "{}", format!("- {}", e).red())?;
+                }
+                if let Some(a) = a {
+                    writeln!(out, "{}", format!("+ {}", a).green())?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{ensure, Context};
+use serde::{Deserialize, Serialize};
+
+use crate::{inputs::Inputs, runner::DaySelection, solvers, Day, Puzzle, Solver};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    First,
+    Second,
+}
+
+impl From<Puzzle> for Stage {
+    fn from(puzzle: Puzzle) -> Self {
+        match puzzle {
+            Puzzle::First => Stage::First,
+            Puzzle::Second => Stage::Second,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::First => write!(f, "first"),
+            Stage::Second => write!(f, "second"),
+        }
+    }
+}
+
+/// Timing statistics of a single stage, all durations are in nanoseconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: Day,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub version: String,
+    pub measurements: Vec<Measurement>,
+    /// Days which could not be benchmarked, with the reason.
+    pub failures: Vec<(Day, String)>,
+}
+
+impl BenchReport {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Can not open '{}'", path.display()))?;
+        let report = serde_json::from_reader(io::BufReader::new(file))
+            .with_context(|| {
+                format!("Invalid benchmark file '{}'", path.display())
+            })?;
+        Ok(report)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("Can not create '{}'", path.display()))?;
+        serde_json::to_writer_pretty(io::BufWriter::new(file), self)?;
+        Ok(())
+    }
+}
+
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+fn measure(
+    day: Day,
+    stage: Stage,
+    config: &BenchConfig,
+    mut f: impl FnMut() -> anyhow::Result<()>,
+) -> anyhow::Result<Measurement> {
+    for _ in 0..config.warmup {
+        f()?;
+    }
+
+    let mut times = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations {
+        let start = Instant::now();
+        f()?;
+        times.push(start.elapsed());
+    }
+
+    times.sort();
+
+    let nanos = |d: Duration| d.as_nanos().try_into().unwrap_or(u64::MAX);
+    let p95_idx = (times.len() * 95).div_ceil(100) - 1;
+
+    Ok(Measurement {
+        day,
+        stage,
+        iterations: config.iterations,
+        min_ns: nanos(times[0]),
+        median_ns: nanos(times[times.len() / 2]),
+        p95_ns: nanos(times[p95_idx]),
+    })
+}
+
+fn bench_solver(
+    solver: &dyn Solver,
+    text: &str,
+    config: &BenchConfig,
+) -> anyhow::Result<Vec<Measurement>> {
+    let day = solver.day();
+    let mut result = Vec::new();
+
+    result.push(measure(day, Stage::Parse, config, || {
+        solver.parse(text).map(drop)
+    })?);
+
+    let parsed = solver.parse(text)?;
+
+    for &puzzle in solver.puzzles() {
+        result.push(measure(day, puzzle.into(), config, || {
+            solver.solve(&parsed, puzzle).map(drop)
+        })?);
+    }
+
+    Ok(result)
+}
+
+/// Benchmarks parsing and solving every puzzle of the selected days.
+pub fn run_bench(
+    days: &DaySelection,
+    inputs: &Inputs,
+    suffix: &str,
+    config: &BenchConfig,
+) -> anyhow::Result<BenchReport> {
+    ensure!(config.iterations > 0, "At least one iteration is required.");
+
+    let mut measurements = Vec::new();
+    let mut failures = Vec::new();
+
+    for solver in solvers().filter(|s| days.days().contains(&s.day())) {
+        let path = inputs.path(solver.day(), suffix);
+        let result = std::fs::read_to_string(&path)
+            .with_context(|| format!("Can not read '{}'", path.display()))
+            .and_then(|text| bench_solver(solver, &text, config));
+
+        match result {
+            Ok(m) => measurements.extend(m),
+            Err(e) => failures.push((solver.day(), format!("{:#}", e))),
+        }
+    }
+
+    Ok(BenchReport {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        measurements,
+        failures,
+    })
+}
+
+/// Prints the measurements, together with the change of the median relative to a
+/// previous run if one is given.
+pub fn print_report(
+    report: &BenchReport,
+    baseline: Option<&BenchReport>,
+    mut out: impl Write,
+) -> io::Result<()> {
+    let baseline: HashMap<_, _> = baseline
+        .iter()
+        .flat_map(|b| &b.measurements)
+        .map(|m| ((m.day, m.stage), m.median_ns))
+        .collect();
+
+    let fmt_ns = |ns: u64| format!("{:.2?}", Duration::from_nanos(ns));
+
+    write!(
+        out,
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "Day", "Stage", "Min", "Median", "P95"
+    )?;
+    if !baseline.is_empty() {
+        write!(out, "  {:>8}", "Change")?;
+    }
+    writeln!(out)?;
+
+    for m in &report.measurements {
+        write!(
+            out,
+            "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+            m.day.0,
+            m.stage.to_string(),
+            fmt_ns(m.min_ns),
+            fmt_ns(m.median_ns),
+            fmt_ns(m.p95_ns)
+        )?;
+
+        if let Some(&old) = baseline.get(&(m.day, m.stage)) {
+            let change = (m.median_ns as f64 / old.max(1) as f64 - 1.0) * 100.0;
+            write!(out, "  {:>+7.1}%", change)?;
+        }
+        writeln!(out)?;
+    }
+
+    for (day, error) in &report.failures {
+        writeln!(out, "{:>3}  error: {}", day.0, error)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{inputs::Inputs, record::sha256, runner::Report, Answer, Day, Puzzle};
+
+/// Name of the cache file in the inputs directory.
+pub const FILE_NAME: &str = "aoc2021-cache.json";
+
+/// Environment variable with the path of the cache file.
+pub const PATH_VAR: &str = "AOC2021_CACHE";
+
+/// Path of the cache file, from [`PATH_VAR`] or [`FILE_NAME`] in the directory
+/// of `inputs`. `None` if that directory does not exist, so runs outside of the
+/// project leave no cache behind.
+pub fn path(inputs: &Inputs) -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(PATH_VAR) {
+        return Some(PathBuf::from(path));
+    }
+
+    inputs.dir().is_dir().then(|| inputs.dir().join(FILE_NAME))
+}
+
+/// The version of the solvers, answers of other versions are never reused.
+pub fn version() -> String {
+    match option_env!("AOC2021_COMMIT") {
+        Some(commit) => format!("{}+{}", env!("CARGO_PKG_VERSION"), commit),
+        None => env!("CARGO_PKG_VERSION").to_string(),
+    }
+}
+
+/// Identifies an answer by the puzzle, its input text and the solvers.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct CacheKey {
+    pub day: Day,
+    pub puzzle: Puzzle,
+    pub input_sha256: String,
+    pub version: String,
+}
+
+impl CacheKey {
+    pub fn new(day: Day, puzzle: Puzzle, text: &str) -> Self {
+        Self {
+            day,
+            puzzle,
+            input_sha256: sha256(text),
+            version: version(),
+        }
+    }
+
+    /// The key of a puzzle on the input file `path`, `None` if it can not be read.
+    pub fn for_file(day: Day, puzzle: Puzzle, path: &Path) -> Option<Self> {
+        let text = fs::read_to_string(path).ok()?;
+        Some(Self::new(day, puzzle, &text))
+    }
+}
+
+/// An answer with the times it took when it was computed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cached {
+    pub answer: Answer,
+    pub parse_time: Option<Duration>,
+    pub time: Duration,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    #[serde(flatten)]
+    key: CacheKey,
+    #[serde(flatten)]
+    cached: Cached,
+}
+
+/// Answers of earlier runs, so puzzles are only solved again when their input or
+/// the solvers change. Only successful answers are cached.
+///
+/// A cache without a file, like the default one, is never saved.
+#[derive(Debug, Clone, Default)]
+pub struct Cache {
+    path: Option<PathBuf>,
+    entries: HashMap<CacheKey, Cached>,
+}
+
+impl Cache {
+    /// Loads the cache at [`path`], without a file if there is no such path.
+    pub fn locate(inputs: &Inputs) -> anyhow::Result<Self> {
+        match path(inputs) {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Loads the cache from `path`, which is empty if the file does not exist.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let mut cache = Self {
+            path: Some(path.to_path_buf()),
+            entries: HashMap::new(),
+        };
+
+        if !path.exists() {
+            return Ok(cache);
+        }
+
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Can not read '{}'", path.display()))?;
+        let entries: Vec<Entry> =
+            serde_json::from_str(&text).with_context(|| {
+                format!(
+                    "Invalid cache file '{}', run `aoc2021 invalidate` to reset it",
+                    path.display()
+                )
+            })?;
+
+        cache.entries = entries.into_iter().map(|e| (e.key, e.cached)).collect();
+        Ok(cache)
+    }
+
+    /// Writes the cache back to the file it was loaded from.
+    pub fn save(&self) -> anyhow::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let mut entries: Vec<_> = self
+            .entries
+            .iter()
+            .map(|(key, cached)| Entry {
+                key: key.clone(),
+                cached: cached.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+
+        // Written to a temporary file first, so a concurrent run never reads half
+        // of it.
+        let temporary = path.with_extension("json.tmp");
+        fs::write(&temporary, serde_json::to_string_pretty(&entries)?)
+            .with_context(|| format!("Can not write '{}'", temporary.display()))?;
+        fs::rename(&temporary, path)
+            .with_context(|| format!("Can not write '{}'", path.display()))
+    }
+
+    pub fn get(&self, key: &CacheKey) -> Option<&Cached> {
+        self.entries.get(key)
+    }
+
+    pub fn insert(&mut self, key: CacheKey, cached: Cached) {
+        self.entries.insert(key, cached);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The cached report of a puzzle on the input file `path`.
+    pub fn lookup(&self, day: Day, puzzle: Puzzle, path: &Path) -> Option<Report> {
+        let key = CacheKey::for_file(day, puzzle, path)?;
+        let cached = self.get(&key)?;
+
+        Some(Report {
+            day,
+            puzzle,
+            answer: Ok(cached.answer.clone()),
+            time: cached.time,
+            parse_time: cached.parse_time,
+            cached: true,
+            input_sha256: Some(key.input_sha256),
+        })
+    }
+
+    /// Caches the answer of a report under the hash of the input it solved.
+    pub fn store(&mut self, report: &Report) {
+        let (answer, input_sha256) = match (&report.answer, &report.input_sha256) {
+            (Ok(answer), Some(input_sha256)) if !report.cached => {
+                (answer.clone(), input_sha256.clone())
+            }
+            _ => return,
+        };
+
+        let key = CacheKey {
+            day: report.day,
+            puzzle: report.puzzle,
+            input_sha256,
+            version: version(),
+        };
+        let cached = Cached {
+            answer,
+            parse_time: report.parse_time,
+            time: report.time,
+        };
+        self.insert(key, cached);
+    }
+
+    /// Removes the answers of `day`, or of every day. Returns how many were
+    /// removed.
+    pub fn invalidate(&mut self, day: Option<Day>) -> usize {
+        let before = self.entries.len();
+        self.entries
+            .retain(|key, _| day.is_some_and(|day| day != key.day));
+        before - self.entries.len()
+    }
+}
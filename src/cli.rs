@@ -0,0 +1,653 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    num::NonZeroUsize,
+    path::PathBuf,
+    str::FromStr,
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, ensure, Context};
+use structopt::{clap::AppSettings, StructOpt};
+use tracing::Level;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+use crate::{
+    answers::{self, Answers},
+    bench::{self, BenchConfig, BenchReport},
+    cache::{Cache, CacheKey},
+    inputs::Inputs,
+    raster::{ExportConfig, ImageDirectory, ImageFormat, Palette},
+    record::Record,
+    runner::{self, DaySelection, LimitError, Limits, RunConfig},
+    solvers,
+    visualize::{Canvas, Directory, Terminal},
+    watch::Watch,
+    Day, InputSource, Params, Puzzle,
+};
+
+#[derive(StructOpt)]
+#[structopt(name = "aoc2021", setting = AppSettings::SubcommandsNegateReqs)]
+pub struct Opt {
+    /// Puzzle input file, `-` reads the input from stdin.
+    #[structopt(name = "FILE", conflicts_with = "input")]
+    pub file: Option<PathBuf>,
+
+    /// Name of the input in the inputs directory, e.g. `small` for `dNN_small.txt`.
+    /// Defaults to `main` if neither a file nor text is given.
+    #[structopt(short, long)]
+    pub input: Option<String>,
+
+    /// Puzzle input given inline instead of as a file.
+    #[structopt(long, conflicts_with_all = &["FILE", "input"])]
+    pub text: Option<String>,
+
+    #[structopt(short, long, required_unless_one = &["all", "days"])]
+    pub day: Option<Day>,
+
+    #[structopt(default_value, short, long)]
+    pub puzzle: Puzzle,
+
+    /// Change a constant of the puzzle, e.g. `--param days=256`. The `params`
+    /// command lists the parameters of every puzzle.
+    #[structopt(
+        long = "param",
+        parse(try_from_str = parse_param),
+        number_of_values = 1,
+        conflicts_with_all = &["all", "days", "visualize", "play"]
+    )]
+    pub params: Vec<(String, String)>,
+
+    /// Run every puzzle of every day.
+    #[structopt(long, conflicts_with = "days")]
+    pub all: bool,
+
+    /// Run every puzzle of the given days, e.g. `3-9,15`.
+    #[structopt(long)]
+    pub days: Option<DaySelection>,
+
+    /// Input used when running several days, read from `dNN_<suffix>.txt` in the
+    /// inputs directory.
+    #[structopt(long, default_value = "main")]
+    pub suffix: String,
+
+    /// Threads running the puzzles of several days, defaults to the number of CPUs.
+    /// Every puzzle is still timed on its own.
+    #[structopt(short, long)]
+    pub jobs: Option<NonZeroUsize>,
+
+    /// Stop a puzzle of several days after this many seconds and report it as timed
+    /// out.
+    #[structopt(long)]
+    pub timeout: Option<f64>,
+
+    /// Stop a puzzle of several days when it allocates more than this many MiB and
+    /// report it as out of memory.
+    #[structopt(long)]
+    pub memory_limit: Option<u64>,
+
+    /// How the answers are printed, `plain` or `json`. JSON has a line for every
+    /// puzzle with its input, answer or error, and timings.
+    #[structopt(long, default_value = "plain")]
+    pub format: OutputFormat,
+
+    /// Draw the intermediate states of the solution before printing the answer.
+    #[structopt(long, conflicts_with_all = &["all", "days"])]
+    pub visualize: bool,
+
+    /// Write the frames of `--visualize` as numbered files into this directory
+    /// instead of printing them.
+    #[structopt(long, requires = "visualize")]
+    pub frames_dir: Option<PathBuf>,
+
+    /// Render the frames as images into `--frames-dir`, either a `png` file for
+    /// every frame or a single animated `gif`.
+    #[structopt(long, requires = "frames-dir")]
+    pub image_format: Option<ImageFormat>,
+
+    /// Pixels per cell of rendered frames, defaults to 4.
+    #[structopt(long, requires = "image-format")]
+    pub scale: Option<usize>,
+
+    /// Milliseconds between the frames of an animated gif, defaults to 100.
+    #[structopt(long, requires = "image-format")]
+    pub interval: Option<u32>,
+
+    /// TOML file with the colors of rendered frames.
+    #[structopt(long, requires = "image-format")]
+    pub palette: Option<PathBuf>,
+
+    /// Play the simulation of the puzzle step by step in the terminal before
+    /// printing the answer.
+    #[structopt(long, conflicts_with_all = &["all", "days", "visualize"])]
+    pub play: bool,
+
+    /// Solve the puzzle again whenever its input file or the answer manifest
+    /// changes, showing the answer, its time and whether it is the expected one.
+    #[structopt(long, conflicts_with_all = &["all", "days", "text", "visualize", "play"])]
+    pub watch: bool,
+
+    /// Manifest of expected answers for `--watch`, defaults to `res/answers.toml`
+    /// if it exists.
+    #[structopt(long, requires = "watch")]
+    pub answers: Option<PathBuf>,
+
+    /// Solve the puzzles even if the answer cache has their answers for the same
+    /// input and version, the new answers are still cached.
+    #[structopt(long)]
+    pub no_cache: bool,
+
+    /// Log the parsing and solving of the puzzles to stderr, `-vv` and `-vvv` log
+    /// more details.
+    #[structopt(short, long, parse(from_occurrences), global = true)]
+    pub verbose: u8,
+
+    #[structopt(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Plain,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(OutputFormat::Plain),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(anyhow!("{} is an invalid output format.", s)),
+        }
+    }
+}
+
+#[derive(StructOpt)]
+pub enum Command {
+    /// Benchmark parsing and solving the puzzles of the selected days.
+    Bench(BenchOpt),
+
+    /// Check answers against the expected answers of the manifest.
+    Verify(VerifyOpt),
+
+    /// List the names of the inputs of every day.
+    ///
+    /// Inputs are read from the directory in the environment variable
+    /// `AOC2021_INPUTS`, otherwise from `inputs` in `aoc2021.toml`, otherwise from
+    /// `res`.
+    Inputs(InputsOpt),
+
+    /// List the parameters which `--param` changes, with their values in the
+    /// first and second puzzle.
+    Params(ParamsOpt),
+
+    /// Remove answers from the cache, so the puzzles are solved again.
+    Invalidate(InvalidateOpt),
+
+    /// Explore the parsed input of a day with commands read from stdin, e.g.
+    /// `step 10` and `show`. `help` lists the commands of the day.
+    Repl(ReplOpt),
+
+    /// Solve a single puzzle in its own process for `--timeout` and
+    /// `--memory-limit`.
+    #[structopt(setting = AppSettings::Hidden)]
+    Worker(WorkerOpt),
+}
+
+#[derive(StructOpt)]
+pub struct BenchOpt {
+    /// Days to benchmark, e.g. `3-9,15`. Defaults to every day.
+    #[structopt(long)]
+    pub days: Option<DaySelection>,
+
+    /// Input used for benchmarking, read from `dNN_<suffix>.txt` in the inputs
+    /// directory.
+    #[structopt(long, default_value = "main")]
+    pub suffix: String,
+
+    /// Untimed runs before measuring.
+    #[structopt(long, default_value = "3")]
+    pub warmup: usize,
+
+    /// Timed runs of every stage.
+    #[structopt(short = "n", long, default_value = "10")]
+    pub iterations: usize,
+
+    /// Write the results as JSON to this file.
+    #[structopt(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Show the change relative to the results of a previous run.
+    #[structopt(long)]
+    pub compare: Option<PathBuf>,
+}
+
+#[derive(StructOpt)]
+pub struct VerifyOpt {
+    /// Input file to check, named `dNN_<input>.txt`. Without it every answer of the
+    /// manifest is checked.
+    pub input: Option<PathBuf>,
+
+    /// Only check this day, overrides the day derived from the file name.
+    #[structopt(short, long)]
+    pub day: Option<Day>,
+
+    /// Only check this puzzle.
+    #[structopt(short, long)]
+    pub puzzle: Option<Puzzle>,
+
+    /// Also check answers which are marked as slow.
+    #[structopt(long)]
+    pub slow: bool,
+
+    /// Manifest of expected answers.
+    #[structopt(long, default_value = answers::DEFAULT_PATH)]
+    pub answers: PathBuf,
+}
+
+#[derive(StructOpt)]
+pub struct InputsOpt {
+    /// Only list the inputs of this day.
+    #[structopt(short, long)]
+    pub day: Option<Day>,
+}
+
+#[derive(StructOpt)]
+pub struct ParamsOpt {
+    /// Only list the parameters of this day.
+    #[structopt(short, long)]
+    pub day: Option<Day>,
+}
+
+#[derive(StructOpt)]
+pub struct InvalidateOpt {
+    /// Only remove the answers of this day.
+    #[structopt(short, long)]
+    pub day: Option<Day>,
+}
+
+#[derive(StructOpt)]
+pub struct ReplOpt {
+    #[structopt(short, long)]
+    pub day: Day,
+
+    /// Puzzle input file, defaults to the `main` input.
+    #[structopt(name = "FILE", conflicts_with = "input")]
+    pub file: Option<PathBuf>,
+
+    /// Name of the input in the inputs directory, e.g. `small` for `dNN_small.txt`.
+    #[structopt(short, long)]
+    pub input: Option<String>,
+}
+
+#[derive(StructOpt)]
+pub struct WorkerOpt {
+    #[structopt(short, long)]
+    pub day: Day,
+
+    #[structopt(short, long)]
+    pub puzzle: Puzzle,
+
+    pub file: PathBuf,
+}
+
+fn run_inputs(opt: InputsOpt) -> anyhow::Result<()> {
+    let inputs = Inputs::locate()?;
+    let list = inputs.list()?;
+
+    let mut out = io::stdout().lock();
+    writeln!(out, "Inputs in '{}':", inputs.dir().display())?;
+    for (day, names) in list {
+        if opt.day.is_none_or(|d| d == day) {
+            writeln!(out, "Day {:>2}: {}", day.to_string(), names.join(", "))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn run_params(opt: ParamsOpt) -> anyhow::Result<()> {
+    let mut out = io::stdout().lock();
+    for solver in solvers().filter(|s| opt.day.is_none_or(|d| d == s.day())) {
+        for param in solver.params() {
+            writeln!(
+                out,
+                "Day {:>2}: {:<8} {:>6} {:>6}  {}",
+                solver.day().to_string(),
+                param.name,
+                param.first.unwrap_or("-"),
+                param.second.unwrap_or("-"),
+                param.description
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Saves the answers of a run after they were printed. Failing to save only
+/// loses the cached answers, so it is a warning.
+fn save_cache(cache: &Cache) {
+    if let Err(e) = cache.save() {
+        eprintln!("Warning: {:#}", e);
+    }
+}
+
+fn run_invalidate(opt: InvalidateOpt) -> anyhow::Result<()> {
+    let mut cache = Cache::locate(&Inputs::locate()?)?;
+    let removed = cache.invalidate(opt.day);
+    cache.save()?;
+
+    println!("Removed {} cached answers.", removed);
+    Ok(())
+}
+
+fn run_repl(opt: ReplOpt) -> anyhow::Result<()> {
+    let source = match (opt.file, opt.input) {
+        (Some(path), _) => path.into(),
+        (None, name) => Inputs::locate()?
+            .find(opt.day, name.as_deref().unwrap_or("main"))?
+            .into(),
+    };
+    let text = match source {
+        InputSource::Stdin => bail!(
+            "The REPL reads its commands from stdin, the input has to be a file."
+        ),
+        source => source.read()?,
+    };
+
+    let stdin = io::stdin();
+    let prompt = stdin.is_terminal();
+    crate::inspect(opt.day, &text, stdin.lock(), io::stdout().lock(), prompt)
+}
+
+fn run_verify(opt: VerifyOpt) -> anyhow::Result<()> {
+    let manifest = Answers::load(&opt.answers)?;
+
+    let file = match &opt.input {
+        Some(path) => {
+            let (day, name) = answers::parse_input_name(path).ok_or(anyhow!(
+                "Can not derive day and input name from '{}', expected 'dNN_<input>.txt'.",
+                path.display()
+            ))?;
+            Some((path, opt.day.unwrap_or(day), name))
+        }
+        None => None,
+    };
+
+    let checks: Vec<_> = manifest
+        .iter()
+        .filter(|(key, _)| match &file {
+            Some((_, day, name)) => key.day == *day && key.input == *name,
+            None => opt.day.is_none_or(|day| key.day == day),
+        })
+        .filter(|(key, _)| opt.puzzle.is_none_or(|p| key.puzzle == p))
+        .filter(|(_, expected)| opt.slow || file.is_some() || !expected.slow)
+        .map(|(key, expected)| {
+            let path = match &file {
+                Some((path, ..)) => path.to_path_buf(),
+                None => runner::input_path(key.day, &key.input),
+            };
+            answers::check(&path, key.clone(), expected.clone())
+        })
+        .collect();
+
+    ensure!(
+        !checks.is_empty(),
+        "There are no expected answers to check."
+    );
+
+    answers::print_checks(&checks, io::stdout().lock())?;
+
+    let failed = checks.iter().filter(|c| !c.passed()).count();
+    if failed > 0 {
+        bail!("{} of {} answers are wrong.", failed, checks.len());
+    }
+
+    Ok(())
+}
+
+fn run_bench(opt: BenchOpt) -> anyhow::Result<()> {
+    let baseline = opt.compare.as_deref().map(BenchReport::load).transpose()?;
+
+    let days = opt.days.unwrap_or_else(DaySelection::all);
+    let config = BenchConfig {
+        warmup: opt.warmup,
+        iterations: opt.iterations,
+    };
+
+    let report = bench::run_bench(&days, &Inputs::locate()?, &opt.suffix, &config)?;
+    bench::print_report(&report, baseline.as_ref(), io::stdout().lock())?;
+
+    if let Some(output) = opt.output {
+        report.save(&output)?;
+    }
+
+    Ok(())
+}
+
+fn limits(
+    timeout: Option<f64>,
+    memory_limit: Option<u64>,
+) -> anyhow::Result<Option<Limits>> {
+    if timeout.is_none() && memory_limit.is_none() {
+        return Ok(None);
+    }
+    if cfg!(not(unix)) {
+        return Err(LimitError::Unsupported.into());
+    }
+
+    let timeout = timeout
+        .map(|secs| {
+            Duration::try_from_secs_f64(secs)
+                .ok()
+                .filter(|t| !t.is_zero())
+                .ok_or(anyhow!(
+                    "The timeout has to be a positive number of seconds."
+                ))
+        })
+        .transpose()?;
+    let memory = memory_limit
+        .map(|mib| {
+            mib.checked_mul(1024 * 1024)
+                .filter(|&bytes| bytes > 0)
+                .ok_or(anyhow!(
+                    "The memory limit has to be a positive number of MiB."
+                ))
+        })
+        .transpose()?;
+
+    Ok(Some(Limits {
+        program: std::env::current_exe().context("Can not locate the executable")?,
+        timeout,
+        memory,
+    }))
+}
+
+fn export_config(
+    palette: Option<PathBuf>,
+    scale: Option<usize>,
+    interval: Option<u32>,
+) -> anyhow::Result<ExportConfig> {
+    let mut config = ExportConfig::default();
+
+    if let Some(path) = palette {
+        config.palette = Palette::load(path)?;
+    }
+    if let Some(scale) = scale {
+        ensure!(scale > 0, "The scale has to be at least 1.");
+        config.scale = scale;
+    }
+    if let Some(interval) = interval {
+        config.interval = interval;
+    }
+
+    Ok(config)
+}
+
+fn parse_param(param: &str) -> anyhow::Result<(String, String)> {
+    let (name, value) = param.split_once('=').ok_or(anyhow!(
+        "Parameter '{}' is not of the form key=value.",
+        param
+    ))?;
+
+    Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => return,
+        1 => Level::INFO,
+        2 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+
+    // Closing spans logs the time of every phase.
+    tracing_subscriber::fmt()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
+}
+
+pub fn run(opt: Opt) -> anyhow::Result<()> {
+    init_logging(opt.verbose);
+
+    if let Some(command) = opt.command {
+        return match command {
+            Command::Bench(bench) => run_bench(bench),
+            Command::Verify(verify) => run_verify(verify),
+            Command::Inputs(inputs) => run_inputs(inputs),
+            Command::Params(params) => run_params(params),
+            Command::Invalidate(invalidate) => run_invalidate(invalidate),
+            Command::Repl(repl) => run_repl(repl),
+            Command::Worker(worker) => runner::run_worker(
+                worker.day,
+                worker.puzzle,
+                &worker.file,
+                io::stdout().lock(),
+            ),
+        };
+    }
+
+    let selection = match opt.days {
+        Some(days) => Some(days),
+        None if opt.all => Some(DaySelection::all()),
+        None => None,
+    };
+
+    let inputs = Inputs::locate()?;
+    let mut cache = Cache::locate(&inputs)?;
+
+    if let Some(days) = selection {
+        let config = RunConfig {
+            jobs: opt
+                .jobs
+                .or_else(|| thread::available_parallelism().ok())
+                .unwrap_or(NonZeroUsize::MIN),
+            limits: limits(opt.timeout, opt.memory_limit)?,
+            cache: (!opt.no_cache).then(|| cache.clone()),
+        };
+        let reports =
+            runner::run_days_parallel(&days, &inputs, &opt.suffix, &config);
+
+        let mut out = io::stdout().lock();
+        match opt.format {
+            OutputFormat::Plain => runner::print_table(&reports, &mut out)?,
+            OutputFormat::Json => {
+                for report in &reports {
+                    let path = inputs.path(report.day, &opt.suffix);
+                    Record::from_report(report, &path).write(&mut out)?;
+                }
+            }
+        }
+
+        for report in &reports {
+            cache.store(report);
+        }
+        save_cache(&cache);
+        return Ok(());
+    }
+
+    let day = opt.day.ok_or(anyhow!("Missing day."))?;
+    let source = match (opt.text, opt.file) {
+        (Some(text), _) => InputSource::Text(text),
+        (None, Some(path)) => path.into(),
+        (None, None) => {
+            let name = opt.input.as_deref().unwrap_or("main");
+            inputs.find(day, name)?.into()
+        }
+    };
+    let params: Params = opt.params.into_iter().collect();
+
+    if opt.watch {
+        let input = match source {
+            InputSource::File(path) => path,
+            _ => bail!("Watching needs an input file."),
+        };
+        let answers = opt.answers.or_else(|| {
+            let path = PathBuf::from(answers::DEFAULT_PATH);
+            path.exists().then_some(path)
+        });
+
+        let watch = Watch {
+            day,
+            puzzle: opt.puzzle,
+            input,
+            answers,
+            params,
+        };
+        return watch.run(io::stdout().lock());
+    }
+
+    let text = source.read()?;
+
+    if opt.visualize {
+        let mut canvas: Box<dyn Canvas> = match (&opt.frames_dir, opt.image_format) {
+            (Some(dir), Some(format)) => {
+                let config = export_config(opt.palette, opt.scale, opt.interval)?;
+                Box::new(ImageDirectory::create(dir, format, config)?)
+            }
+            (Some(dir), None) => Box::new(Directory::create(dir)?),
+            (None, _) => Box::new(Terminal::stdout()),
+        };
+        crate::visualize(day, opt.puzzle, &text, canvas.as_mut())?;
+    }
+
+    if opt.play {
+        crate::play(day, opt.puzzle, &text)?;
+    }
+
+    let path = match &source {
+        InputSource::File(path) => Some(path.as_path()),
+        _ => None,
+    };
+
+    // Answers with changed parameters are neither cached nor taken from the cache.
+    let key = CacheKey::new(day, opt.puzzle, &text);
+    let record = match cache.get(&key) {
+        Some(cached) if !opt.no_cache && params.is_empty() => {
+            Record::from_cache(path, &key, cached)
+        }
+        _ => Record::solve(day, opt.puzzle, path, &text, &params),
+    };
+
+    match (opt.format, &record.answer) {
+        (OutputFormat::Plain, Some(answer)) => println!("{}", answer),
+        (OutputFormat::Plain, None) => {}
+        (OutputFormat::Json, _) => record.write(io::stdout().lock())?,
+    }
+
+    if let Some(cached) = record.to_cache().filter(|_| params.is_empty()) {
+        cache.insert(key, cached);
+        save_cache(&cache);
+    }
+
+    match record.error {
+        Some(error) => bail!(error),
+        None => Ok(()),
+    }
+}
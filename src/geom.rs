@@ -0,0 +1,384 @@
+use std::ops::{
+    Add, AddAssign, Index, IndexMut, Mul, Neg, RangeInclusive, Sub, SubAssign,
+};
+
+use itertools::iproduct;
+use num::{PrimInt, Signed};
+
+/// A point, or the vector between two points, with `N` coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T, const N: usize>(pub [T; N]);
+
+pub type Point2<T> = Point<T, 2>;
+pub type Point3<T> = Point<T, 3>;
+
+/// Distance of two coordinates, which does not overflow for unsigned ones.
+fn distance<T: PrimInt>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T: Copy, const N: usize> Point<T, N> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Point<U, N> {
+        Point(self.0.map(f))
+    }
+
+    /// Combines the coordinates of both points axis by axis.
+    pub fn zip_with<U: Copy, V>(
+        self,
+        other: Point<U, N>,
+        mut f: impl FnMut(T, U) -> V,
+    ) -> Point<V, N> {
+        Point(std::array::from_fn(|axis| f(self.0[axis], other.0[axis])))
+    }
+}
+
+impl<T: PrimInt, const N: usize> Point<T, N> {
+    pub fn origin() -> Self {
+        Self([T::zero(); N])
+    }
+
+    /// Sum of the distances along every axis.
+    pub fn manhattan(self, other: Self) -> T {
+        self.0
+            .iter()
+            .zip(other.0)
+            .fold(T::zero(), |sum, (&a, b)| sum + distance(a, b))
+    }
+
+    /// Largest distance along any axis, i.e. the moves of a king in chess.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.0
+            .iter()
+            .zip(other.0)
+            .fold(T::zero(), |max, (&a, b)| max.max(distance(a, b)))
+    }
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self([x, y])
+    }
+}
+
+impl<T: Copy> Point2<T> {
+    pub fn x(self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: PrimInt + Signed> Point2<T> {
+    /// The four points sharing an edge with this one, in the order of
+    /// [`Direction::ALL`].
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self + direction.offset())
+    }
+
+    /// The eight points sharing an edge or a corner with this one.
+    pub fn adjacent(self) -> impl Iterator<Item = Self> {
+        self.window().filter(move |&p| p != self)
+    }
+
+    /// The 3x3 square centered on this point, row by row from the top left.
+    pub fn window(self) -> impl Iterator<Item = Self> {
+        let deltas = [-T::one(), T::zero(), T::one()];
+
+        iproduct!(deltas, deltas).map(move |(dy, dx)| self + Point2::new(dx, dy))
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self([x, y, z])
+    }
+}
+
+impl<T: Copy> Point3<T> {
+    pub fn x(self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(self) -> T {
+        self.0[2]
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Point<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.0[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Point<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.0[axis]
+    }
+}
+
+impl<T, const N: usize> Index<Axis> for Point<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: Axis) -> &T {
+        &self.0[axis.index()]
+    }
+}
+
+impl<T, const N: usize> IndexMut<Axis> for Point<T, N> {
+    fn index_mut(&mut self, axis: Axis) -> &mut T {
+        &mut self.0[axis.index()]
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> Add for Point<T, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> Sub for Point<T, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> AddAssign for Point<T, N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> SubAssign for Point<T, N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Copy + Neg<Output = T>, const N: usize> Neg for Point<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|c| -c)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>, const N: usize> Mul<T> for Point<T, N> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        self.map(|c| c * factor)
+    }
+}
+
+/// An axis of a point, which indexes its coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    pub fn index(self) -> usize {
+        match self {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        }
+    }
+}
+
+/// A direction on a grid, where y grows downwards like the rows of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from the top.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The vector of a step in this direction.
+    pub fn offset<T: PrimInt + Signed>(self) -> Point2<T> {
+        let (zero, one) = (T::zero(), T::one());
+
+        match self {
+            Direction::Up => Point2::new(zero, -one),
+            Direction::Right => Point2::new(one, zero),
+            Direction::Down => Point2::new(zero, one),
+            Direction::Left => Point2::new(-one, zero),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+}
+
+/// The coordinates from `start` to `end` on one axis, both included. The range
+/// is empty if `start` is greater than `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AxisRange<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> AxisRange<T> {
+    pub const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> AxisRange<U> {
+        AxisRange::new(f(self.start), f(self.end))
+    }
+}
+
+impl<T: PrimInt> AxisRange<T> {
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Number of coordinates in the range, `None` if it does not fit into `T`.
+    pub fn len(&self) -> Option<T> {
+        if self.is_empty() {
+            return Some(T::zero());
+        }
+
+        self.end.checked_sub(&self.start)?.checked_add(&T::one())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// Whether every coordinate of `other` is in this range.
+    pub fn contains_range(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// The coordinates in both ranges, `None` if there are none.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let range = Self::new(self.start.max(other.start), self.end.min(other.end));
+
+        (!range.is_empty()).then_some(range)
+    }
+}
+
+impl<T> IntoIterator for AxisRange<T>
+where
+    RangeInclusive<T>: Iterator<Item = T>,
+{
+    type Item = T;
+
+    type IntoIter = RangeInclusive<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.start..=self.end
+    }
+}
+
+/// The points between the corners `min` and `max`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<T, const N: usize> {
+    pub min: Point<T, N>,
+    pub max: Point<T, N>,
+}
+
+impl<T: PrimInt, const N: usize> BoundingBox<T, N> {
+    pub fn new(min: Point<T, N>, max: Point<T, N>) -> Self {
+        Self { min, max }
+    }
+
+    /// The box spanned by a range per axis.
+    pub fn from_ranges(ranges: [AxisRange<T>; N]) -> Self {
+        Self {
+            min: Point(ranges.map(|r| r.start)),
+            max: Point(ranges.map(|r| r.end)),
+        }
+    }
+
+    /// The smallest box around all `points`, `None` if there are none.
+    pub fn from_points(
+        points: impl IntoIterator<Item = Point<T, N>>,
+    ) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(Self::new(first, first), |bounds, p| Self {
+            min: bounds.min.zip_with(p, T::min),
+            max: bounds.max.zip_with(p, T::max),
+        }))
+    }
+
+    /// The range of the box along `axis`.
+    pub fn axis(&self, axis: usize) -> AxisRange<T> {
+        AxisRange::new(self.min[axis], self.max[axis])
+    }
+
+    pub fn ranges(&self) -> [AxisRange<T>; N] {
+        std::array::from_fn(|axis| self.axis(axis))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges().iter().any(AxisRange::is_empty)
+    }
+
+    pub fn contains(&self, p: Point<T, N>) -> bool {
+        self.ranges()
+            .iter()
+            .zip(p.0)
+            .all(|(range, c)| range.contains(c))
+    }
+
+    /// Whether every point of `other` is in this box.
+    pub fn contains_box(&self, other: &Self) -> bool {
+        other.is_empty()
+            || self
+                .ranges()
+                .iter()
+                .zip(other.ranges())
+                .all(|(range, other)| range.contains_range(&other))
+    }
+
+    /// The points in both boxes, `None` if there are none.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut ranges = [AxisRange::new(T::zero(), T::zero()); N];
+
+        for (axis, range) in ranges.iter_mut().enumerate() {
+            *range = self.axis(axis).intersection(&other.axis(axis))?;
+        }
+
+        Some(Self::from_ranges(ranges))
+    }
+}
@@ -2,6 +2,8 @@ use std::ops::{Index, IndexMut};
 
 use itertools::iproduct;
 
+use crate::{geom::Point2, parse::ParseError};
+
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
     width: usize,
@@ -15,8 +17,9 @@ impl<T> Grid<T> {
         let x = x.try_into().ok()?;
         let y = y.try_into().ok()?;
 
-        (x < self.width).then_some(())?;
-        (y < self.height).then_some(())?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
 
         Some(&self.data[x + y * self.width])
     }
@@ -29,12 +32,24 @@ impl<T> Grid<T> {
         let x = x.try_into().ok()?;
         let y = y.try_into().ok()?;
 
-        (x < self.width).then_some(())?;
-        (y < self.height).then_some(())?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
 
         Some(&mut self.data[x + y * self.width])
     }
 
+    pub fn get_point<I: TryInto<usize> + Copy>(&self, p: Point2<I>) -> Option<&T> {
+        self.get(p.x(), p.y())
+    }
+
+    pub fn get_point_mut<I: TryInto<usize> + Copy>(
+        &mut self,
+        p: Point2<I>,
+    ) -> Option<&mut T> {
+        self.get_mut(p.x(), p.y())
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
         self.data.iter()
     }
@@ -72,16 +87,65 @@ impl<T> Grid<T> {
     }
 
     pub fn get_width(&self) -> usize {
-        return self.width;
+        self.width
     }
 
     pub fn get_height(&self) -> usize {
-        return self.height;
+        self.height
     }
 
     pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
         self.data.chunks_exact(self.width)
     }
+
+    /// Parses `grid`, a slice of the puzzle input `text`, with one character per
+    /// cell. Every row has to be as wide as the first one.
+    pub fn parse(
+        text: &str,
+        grid: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut data = Vec::new();
+
+        for line in grid.trim().lines().map(str::trim) {
+            let mut row_width = 0;
+
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::new(
+                        text,
+                        &line[i..i + c.len_utf8()],
+                        format!("Invalid character '{}'", c),
+                    )
+                })?;
+
+                data.push(value);
+                row_width += 1;
+            }
+
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(ParseError::new(
+                    text,
+                    line,
+                    format!("Row has {} columns instead of {}", row_width, width),
+                ));
+            }
+
+            height += 1;
+        }
+
+        let width =
+            width.ok_or_else(|| ParseError::new(text, grid, "The grid is empty"))?;
+
+        Ok(Self {
+            width,
+            height,
+            data,
+        })
+    }
 }
 
 #[allow(unused)]
@@ -129,3 +193,17 @@ where
         self.get_mut(x, y).expect("Index out of bounds.")
     }
 }
+
+impl<T, I: TryInto<usize> + Copy> Index<Point2<I>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point2<I>) -> &Self::Output {
+        self.get_point(p).expect("Index out of bounds.")
+    }
+}
+
+impl<T, I: TryInto<usize> + Copy> IndexMut<Point2<I>> for Grid<T> {
+    fn index_mut(&mut self, p: Point2<I>) -> &mut Self::Output {
+        self.get_point_mut(p).expect("Index out of bounds.")
+    }
+}
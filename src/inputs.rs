@@ -0,0 +1,131 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+use serde::Deserialize;
+
+use crate::{answers, Day};
+
+/// Directory of the inputs unless another one is configured.
+pub const DEFAULT_DIR: &str = "res";
+
+/// Environment variable with the inputs directory.
+pub const DIR_VAR: &str = "AOC2021_INPUTS";
+
+/// Optional config file in the working directory, e.g. `inputs = "../inputs"`.
+pub const CONFIG_PATH: &str = "aoc2021.toml";
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    inputs: Option<PathBuf>,
+}
+
+/// Directory with the puzzle inputs, named `dNN_<name>.txt`.
+///
+/// The name tells the variants of a day apart, e.g. `main` for the real input and
+/// `small` for the example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inputs {
+    dir: PathBuf,
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Self::new(DEFAULT_DIR)
+    }
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory from [`DIR_VAR`], otherwise from the config file at
+    /// [`CONFIG_PATH`], otherwise [`DEFAULT_DIR`].
+    pub fn locate() -> anyhow::Result<Self> {
+        if let Some(dir) = env::var_os(DIR_VAR).filter(|dir| !dir.is_empty()) {
+            return Ok(Self::new(dir));
+        }
+
+        let config = Path::new(CONFIG_PATH);
+        if config.exists() {
+            let text = fs::read_to_string(config)
+                .with_context(|| format!("Can not read '{}'", config.display()))?;
+            let config: Config = toml::from_str(&text)
+                .with_context(|| format!("Invalid config '{}'", CONFIG_PATH))?;
+
+            if let Some(dir) = config.inputs {
+                return Ok(Self::new(dir));
+            }
+        }
+
+        Ok(Self::default())
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the input `name` of `day`, whether it exists or not.
+    pub fn path(&self, day: Day, name: &str) -> PathBuf {
+        self.dir.join(format!("d{:02}_{}.txt", day.0, name))
+    }
+
+    /// Path of the existing input `name` of `day`.
+    pub fn find(&self, day: Day, name: &str) -> anyhow::Result<PathBuf> {
+        let path = self.path(day, name);
+        if path.is_file() {
+            return Ok(path);
+        }
+
+        let variants = self.variants(day)?;
+        if variants.is_empty() {
+            bail!(
+                "There are no inputs for day {} in '{}'.",
+                day,
+                self.dir.display()
+            );
+        }
+
+        bail!(
+            "There is no input '{}' for day {} in '{}', available: {}.",
+            name,
+            day,
+            self.dir.display(),
+            variants.join(", ")
+        )
+    }
+
+    /// Names of the inputs of `day`, sorted.
+    pub fn variants(&self, day: Day) -> anyhow::Result<Vec<String>> {
+        Ok(self.list()?.remove(&day).unwrap_or_default())
+    }
+
+    /// Names of the inputs of every day which has any.
+    pub fn list(&self) -> anyhow::Result<BTreeMap<Day, Vec<String>>> {
+        let entries = fs::read_dir(&self.dir)
+            .with_context(|| format!("Can not read '{}'", self.dir.display()))?;
+
+        let mut inputs: BTreeMap<Day, Vec<String>> = BTreeMap::new();
+        for entry in entries {
+            let path = entry?.path();
+            if !path.is_file() || path.extension().is_none_or(|e| e != "txt") {
+                continue;
+            }
+
+            if let Some((day, name)) = answers::parse_input_name(&path) {
+                inputs.entry(day).or_default().push(name);
+            }
+        }
+
+        for names in inputs.values_mut() {
+            names.sort();
+        }
+
+        Ok(inputs)
+    }
+}
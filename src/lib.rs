@@ -1,58 +1,68 @@
-#![feature(type_alias_impl_trait)]
-#![feature(array_windows)]
-#![feature(drain_filter)]
-#![feature(let_else)]
-#![feature(bool_to_option)]
-#![feature(map_first_last)]
-#![feature(once_cell)]
-
-use std::{fmt, path::PathBuf, str::FromStr};
-
-use anyhow::{anyhow, bail};
-
-mod d01;
-mod d02;
-mod d03;
-mod d04;
-mod d05;
-mod d06;
-mod d07;
-mod d08;
-mod d09;
-mod d10;
-mod d11;
-mod d12;
-mod d13;
-mod d14;
-mod d15;
-mod d16;
-mod d17;
-mod d18;
-mod d19;
-mod d20;
-mod d21;
-mod d22;
-mod d23;
-mod d24;
-mod d25;
+use std::{
+    fmt,
+    io::{self, BufRead, Read, Write},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+
+pub use crate::{
+    answer::Answer,
+    grid::Grid,
+    params::{Param, Params},
+    parse::{Location, ParseError},
+    solution::{solver, solvers, Parsed, Solution, Solver},
+};
+use crate::{repl::Repl, visualize::Canvas};
+
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+#[cfg(feature = "cli")]
+pub mod cli;
+pub mod geom;
 mod graph;
 mod grid;
+pub mod inputs;
+mod params;
+mod parse;
+pub mod raster;
+pub mod record;
+pub mod repl;
+pub mod runner;
+pub mod simulation;
+mod solution;
+/// The solution of every day with its typed input, e.g. to embed the solvers in
+/// other programs:
+///
+/// ```
+/// use aoc2021::{solvers::Day01, Solution};
+///
+/// let depths = Day01::parse("199\n200\n208\n210\n200\n207\n")?;
+/// assert_eq!(Day01::part1(&depths)?, 4);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub mod solvers;
+pub mod visualize;
+pub mod watch;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(try_from = "u32", into = "u32")]
 pub struct Day(u32);
 
 impl Day {
-    pub fn new(day: u32) -> Self {
-        if day > 25 {
-            panic!("Invalid day")
-        } else {
-            Day(day)
-        }
+    pub fn new(day: u32) -> Result<Self, DayParseError> {
+        Self::try_from(day)
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum DayParseError {
+pub enum DayParseError {
     #[error("Input '{0}' is not a number.")]
     NotANumber(String),
 
@@ -61,7 +71,7 @@ enum DayParseError {
 }
 
 impl FromStr for Day {
-    type Err = impl std::error::Error;
+    type Err = DayParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let day: u32 = s
@@ -76,8 +86,45 @@ impl FromStr for Day {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl TryFrom<u32> for Day {
+    type Error = DayParseError;
+
+    fn try_from(day: u32) -> Result<Self, Self::Error> {
+        if day > 25 {
+            return Err(DayParseError::InvalidDay(day));
+        }
+
+        Ok(Day(day))
+    }
+}
+
+impl From<Day> for u32 {
+    fn from(day: Day) -> Self {
+        day.0
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
 pub enum Puzzle {
+    #[default]
     First,
     Second,
 }
@@ -91,12 +138,6 @@ impl fmt::Display for Puzzle {
     }
 }
 
-impl Default for Puzzle {
-    fn default() -> Self {
-        Self::First
-    }
-}
-
 impl FromStr for Puzzle {
     type Err = anyhow::Error;
 
@@ -111,78 +152,113 @@ impl FromStr for Puzzle {
     }
 }
 
-fn select(input: &Input, text: &str) -> anyhow::Result<String> {
-    #[allow(unreachable_patterns)]
-    let result = match (input.day, input.puzzle) {
-        (Day(01), Puzzle::First) => d01::part1(text)?.to_string(),
-        (Day(01), Puzzle::Second) => d01::part2(text)?.to_string(),
-        (Day(02), Puzzle::First) => d02::part1(text)?.to_string(),
-        (Day(02), Puzzle::Second) => d02::part2(text)?.to_string(),
-        (Day(03), Puzzle::First) => d03::part1(text)?.to_string(),
-        (Day(03), Puzzle::Second) => d03::part2(text)?.to_string(),
-        (Day(04), Puzzle::First) => d04::part1(text)?.to_string(),
-        (Day(04), Puzzle::Second) => d04::part2(text)?.to_string(),
-        (Day(05), Puzzle::First) => d05::part1(text)?.to_string(),
-        (Day(05), Puzzle::Second) => d05::part2(text)?.to_string(),
-        (Day(06), Puzzle::First) => d06::part1(text)?.to_string(),
-        (Day(06), Puzzle::Second) => d06::part2(text)?.to_string(),
-        (Day(07), Puzzle::First) => d07::part1(text)?.to_string(),
-        (Day(07), Puzzle::Second) => d07::part2(text)?.to_string(),
-        (Day(08), Puzzle::First) => d08::part1(text)?.to_string(),
-        (Day(08), Puzzle::Second) => d08::part2(text)?.to_string(),
-        (Day(09), Puzzle::First) => d09::part1(text)?.to_string(),
-        (Day(09), Puzzle::Second) => d09::part2(text)?.to_string(),
-        (Day(10), Puzzle::First) => d10::part1(text)?.to_string(),
-        (Day(10), Puzzle::Second) => d10::part2(text)?.to_string(),
-        (Day(11), Puzzle::First) => d11::part1(text)?.to_string(),
-        (Day(11), Puzzle::Second) => d11::part2(text)?.to_string(),
-        (Day(12), Puzzle::First) => d12::part1(text)?.to_string(),
-        (Day(12), Puzzle::Second) => d12::part2(text)?.to_string(),
-        (Day(13), Puzzle::First) => d13::part1(text)?.to_string(),
-        (Day(13), Puzzle::Second) => d13::part2(text)?.to_string(),
-        (Day(14), Puzzle::First) => d14::part1(text)?.to_string(),
-        (Day(14), Puzzle::Second) => d14::part2(text)?.to_string(),
-        (Day(15), Puzzle::First) => d15::part1(text)?.to_string(),
-        (Day(15), Puzzle::Second) => d15::part2(text)?.to_string(),
-        (Day(16), Puzzle::First) => d16::part1(text)?.to_string(),
-        (Day(16), Puzzle::Second) => d16::part2(text)?.to_string(),
-        (Day(17), Puzzle::First) => d17::part1(text)?.to_string(),
-        (Day(17), Puzzle::Second) => d17::part2(text)?.to_string(),
-        (Day(18), Puzzle::First) => d18::part1(text)?.to_string(),
-        (Day(18), Puzzle::Second) => d18::part2(text)?.to_string(),
-        (Day(19), Puzzle::First) => d19::part1(text)?.to_string(),
-        (Day(19), Puzzle::Second) => d19::part2(text)?.to_string(),
-        (Day(20), Puzzle::First) => d20::part1(text)?.to_string(),
-        (Day(20), Puzzle::Second) => d20::part2(text)?.to_string(),
-        (Day(21), Puzzle::First) => d21::part1(text)?.to_string(),
-        (Day(21), Puzzle::Second) => d21::part2(text)?.to_string(),
-        (Day(22), Puzzle::First) => d22::part1(text)?.to_string(),
-        (Day(22), Puzzle::Second) => d22::part2(text)?.to_string(),
-        (Day(23), Puzzle::First) => d23::part1(text)?.to_string(),
-        (Day(23), Puzzle::Second) => d23::part2(text)?.to_string(),
-        (Day(24), Puzzle::First) => d24::part1(text)?.to_string(),
-        (Day(24), Puzzle::Second) => d24::part2(text)?.to_string(),
-        (Day(25), Puzzle::First) => d25::part1(text)?.to_string(),
-        _ => bail!("Not implemented!"),
-    };
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
 
-    Ok(result)
+impl InputSource {
+    pub fn read(&self) -> anyhow::Result<String> {
+        match self {
+            InputSource::File(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Can not read '{}'", path.display())),
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .context("Can not read from stdin")?;
+                Ok(text)
+            }
+            InputSource::Text(text) => Ok(text.clone()),
+        }
+    }
 }
 
-#[derive(structopt::StructOpt)]
-pub struct Input {
-    pub input: PathBuf,
+/// The path `-` stands for stdin.
+impl From<PathBuf> for InputSource {
+    fn from(path: PathBuf) -> Self {
+        if path.as_os_str() == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(path)
+        }
+    }
+}
 
-    #[structopt(short, long)]
+/// Configuration for solving a single puzzle.
+pub struct Input {
+    pub input: InputSource,
     pub day: Day,
-
-    #[structopt(default_value, short, long)]
     pub puzzle: Puzzle,
 }
 
-pub fn run_with_config(input: &Input) -> anyhow::Result<String> {
-    let text = std::fs::read_to_string(&input.input)?;
+pub fn run_with_config(input: &Input) -> anyhow::Result<Answer> {
+    let text = input.input.read()?;
+    solve(input.day, input.puzzle, &text)
+}
 
-    let result = select(input, &text)?;
+/// Solves a puzzle on an input which is already in memory.
+pub fn solve(day: Day, puzzle: Puzzle, text: &str) -> anyhow::Result<Answer> {
+    solve_with(day, puzzle, text, &Params::default())
+}
+
+/// Solves a puzzle with some of its [`Param`]s changed.
+pub fn solve_with(
+    day: Day,
+    puzzle: Puzzle,
+    text: &str,
+    params: &Params,
+) -> anyhow::Result<Answer> {
+    let solver =
+        solver(day).ok_or(anyhow!("There is no solution for day {}.", day))?;
+
+    let parsed = solver.parse(text)?;
+    let result = solver.solve_with(&parsed, puzzle, params)?;
     Ok(result)
 }
+
+/// Draws how a puzzle is solved on `canvas`.
+pub fn visualize(
+    day: Day,
+    puzzle: Puzzle,
+    text: &str,
+    canvas: &mut dyn Canvas,
+) -> anyhow::Result<()> {
+    let solver =
+        solver(day).ok_or(anyhow!("There is no solution for day {}.", day))?;
+
+    let parsed = solver.parse(text)?;
+    solver.visualize(&parsed, puzzle, canvas)?;
+    canvas.finish()
+}
+
+/// Plays the simulation of a puzzle interactively in the terminal.
+#[cfg(feature = "cli")]
+pub fn play(day: Day, puzzle: Puzzle, text: &str) -> anyhow::Result<()> {
+    let solver =
+        solver(day).ok_or(anyhow!("There is no solution for day {}.", day))?;
+
+    let parsed = solver.parse(text)?;
+    let simulation = solver.simulation(&parsed, puzzle)?;
+    simulation::play(simulation)
+}
+
+/// Explores the parsed input of a day with the commands of `commands`, see
+/// [`repl::Inspector`].
+pub fn inspect(
+    day: Day,
+    text: &str,
+    commands: impl BufRead,
+    out: impl Write,
+    prompt: bool,
+) -> anyhow::Result<()> {
+    let solver =
+        solver(day).ok_or(anyhow!("There is no solution for day {}.", day))?;
+
+    let parsed = solver.parse(text)?;
+    let mut repl = Repl::new(day, solver.inspector(&parsed)?);
+    repl.run(commands, out, prompt)
+}
@@ -1,12 +1,6 @@
-use aoc2021::{run_with_config, Input};
+use aoc2021::cli::{self, Opt};
 use structopt::StructOpt;
 
 fn main() -> anyhow::Result<()> {
-    let input = Input::from_args();
-
-    let result = run_with_config(&input)?;
-
-    println!("{}", result);
-
-    Ok(())
+    cli::run(Opt::from_args())
 }
@@ -0,0 +1,105 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, bail};
+use itertools::Itertools;
+
+use crate::{Day, Puzzle};
+
+/// A constant of a puzzle which can be changed, e.g. the number of days the
+/// lanternfish multiply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Value in the first puzzle, `None` if it does not use the parameter.
+    pub first: Option<&'static str>,
+    /// Value in the second puzzle, `None` if it does not use the parameter.
+    pub second: Option<&'static str>,
+}
+
+impl Param {
+    pub fn default(&self, puzzle: Puzzle) -> Option<&'static str> {
+        match puzzle {
+            Puzzle::First => self.first,
+            Puzzle::Second => self.second,
+        }
+    }
+}
+
+/// Values of parameters by name, e.g. from `--param days=256`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// The default values of the parameters `declared` by a puzzle.
+    pub fn defaults(declared: &[Param], puzzle: Puzzle) -> Self {
+        declared
+            .iter()
+            .filter_map(|p| {
+                Some((p.name.to_string(), p.default(puzzle)?.to_string()))
+            })
+            .collect()
+    }
+
+    /// These values on top of the defaults of the parameters `declared` by a
+    /// puzzle, which has to use all of them.
+    pub fn resolve(
+        &self,
+        day: Day,
+        declared: &[Param],
+        puzzle: Puzzle,
+    ) -> anyhow::Result<Self> {
+        let mut params = Self::defaults(declared, puzzle);
+
+        for (name, value) in &self.0 {
+            if !params.0.contains_key(name) {
+                let available = params.0.keys().join(", ");
+                bail!(
+                    "Day {} has no parameter '{}' in the {} puzzle, available: {}.",
+                    day,
+                    name,
+                    puzzle,
+                    if available.is_empty() {
+                        "none"
+                    } else {
+                        &available
+                    }
+                );
+            }
+
+            params.insert(name.clone(), value.clone());
+        }
+
+        Ok(params)
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The value of the parameter `name`, parsed as `T`.
+    pub fn get<T>(&self, name: &str) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self
+            .0
+            .get(name)
+            .ok_or(anyhow!("There is no parameter '{}'.", name))?;
+
+        value.parse().map_err(|e| {
+            anyhow!("Invalid value '{}' of parameter '{}': {}.", value, name, e)
+        })
+    }
+}
+
+impl FromIterator<(String, String)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
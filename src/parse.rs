@@ -0,0 +1,178 @@
+use std::{fmt, ops::Range, str::FromStr};
+
+/// Position in the puzzle input, line and column start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// Location of the byte `offset` in `text`.
+    pub fn at(text: &str, offset: usize) -> Self {
+        let offset = offset.min(text.len());
+        let line_start = line_start(text, offset);
+
+        let line = text.as_bytes()[..offset]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count()
+            + 1;
+        let column = text
+            .get(line_start..offset)
+            .map_or(offset - line_start, |s| s.chars().count())
+            + 1;
+
+        Self { line, column }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Invalid puzzle input.
+///
+/// Remembers the byte span of the offending text and renders the line it is
+/// on with the span underlined:
+///
+/// ```text
+/// Invalid number '2x0' at line 2, column 1.
+///   |
+/// 2 | 2x0
+///   | ^^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    span: Range<usize>,
+    location: Location,
+    line: String,
+    width: usize,
+}
+
+impl ParseError {
+    /// Error about `part`, which has to be a slice of `text`.
+    pub fn new(text: &str, part: &str, message: impl Into<String>) -> Self {
+        let start = offset_of(text, part);
+        let end = (start + part.len()).min(text.len());
+
+        Self::at(text, start..end, message)
+    }
+
+    /// Error about the byte range `span` of `text`.
+    pub fn at(text: &str, span: Range<usize>, message: impl Into<String>) -> Self {
+        let start = span.start.min(text.len());
+        let end = span.end.clamp(start, text.len());
+
+        let line_start = line_start(text, start);
+        let line_end = text.as_bytes()[start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(text.len(), |i| start + i);
+        let line = text.get(line_start..line_end).unwrap_or_default();
+
+        // Multi-line spans are underlined up to the end of their first line.
+        let width = text
+            .get(start..end.min(line_end))
+            .map_or(0, |s| s.chars().count())
+            .max(1);
+
+        Self {
+            message: message.into(),
+            span: start..end,
+            location: Location::at(text, start),
+            line: line.trim_end_matches('\r').to_string(),
+            width,
+        }
+    }
+
+    /// Error about something missing at the end of `text`.
+    pub fn end(text: &str, message: impl Into<String>) -> Self {
+        let end = text.trim_end().len();
+
+        Self::at(text, end..end, message)
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Byte range of the offending text.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    pub fn location(&self) -> Location {
+        self.location
+    }
+
+    /// The offending line with the error underlined by carets.
+    pub fn snippet(&self) -> String {
+        let number = self.location.line.to_string();
+        let margin = " ".repeat(number.len());
+
+        format!(
+            "{margin} |\n{number} | {line}\n{margin} | {indent}{carets}",
+            margin = margin,
+            number = number,
+            line = self.line,
+            indent = " ".repeat(self.location.column - 1),
+            carets = "^".repeat(self.width),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at {}.\n{}",
+            self.message,
+            self.location,
+            self.snippet()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset of the slice `part` in `text`.
+///
+/// Slices which do not point into `text` are placed at its end.
+pub fn offset_of(text: &str, part: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let part = part.as_ptr() as usize;
+
+    if (start..=start + text.len()).contains(&part) {
+        part - start
+    } else {
+        text.len()
+    }
+}
+
+/// Slice of `text` from the start of `first` to the end of `last`, which both
+/// have to be slices of `text`.
+pub fn spanning<'a>(text: &'a str, first: &str, last: &str) -> &'a str {
+    let start = offset_of(text, first);
+    let end = (offset_of(text, last) + last.len()).clamp(start, text.len());
+
+    text.get(start..end).unwrap_or_default()
+}
+
+/// Byte offset of the start of the line containing `offset`.
+fn line_start(text: &str, offset: usize) -> usize {
+    text.as_bytes()[..offset]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1)
+}
+
+/// Parses the slice `part` of `text`, reporting the location of invalid numbers.
+pub fn number<T: FromStr>(text: &str, part: &str) -> Result<T, ParseError> {
+    part.parse().map_err(|_| {
+        ParseError::new(text, part, format!("Invalid number '{}'", part))
+    })
+}
@@ -0,0 +1,340 @@
+use std::{collections::HashMap, fmt, fs, path::PathBuf, str::FromStr};
+
+use anyhow::{anyhow, bail, ensure, Context};
+use owo_colors::AnsiColors;
+use serde::Deserialize;
+
+use crate::visualize::{Canvas, Cell, Frame};
+
+mod gif;
+mod png;
+
+/// Color of a pixel, written as `#rrggbb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// Mixes `self` into `other`, a `ratio` of 1 is `self`, 0 is `other`.
+    fn mix(self, other: Rgb, ratio: f32) -> Rgb {
+        let channel =
+            |a: u8, b: u8| (b as f32 + (a as f32 - b as f32) * ratio).round() as u8;
+
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        ensure!(
+            hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()),
+            "'{}' is not a color like '#1a2b3c'.",
+            s
+        );
+
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16);
+        Ok(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+impl TryFrom<String> for Rgb {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Maps the cells of a frame to colors.
+///
+/// A cell gets the color of its symbol if the palette has one, otherwise the
+/// color of its terminal color. `.` and spaces are background. Digits which are
+/// not bold are shaded from dark for `0` to the full color for `9`, so e.g.
+/// heights and energy levels stay visible.
+///
+/// Palettes are loaded from TOML files like
+///
+/// ```toml
+/// background = "#0f0f23"
+/// foreground = "#cccccc"
+///
+/// [colors]
+/// cyan = "#00cc99"
+///
+/// [symbols]
+/// "#" = "#ffff66"
+/// ```
+///
+/// where the keys of `colors` are lowercase terminal colors, e.g. `brightred`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Palette {
+    pub background: Rgb,
+    pub foreground: Rgb,
+    pub colors: HashMap<String, Rgb>,
+    pub symbols: HashMap<char, Rgb>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            background: Rgb(0x0f, 0x0f, 0x23),
+            foreground: Rgb(0xcc, 0xcc, 0xcc),
+            colors: HashMap::new(),
+            symbols: HashMap::new(),
+        }
+    }
+}
+
+impl Palette {
+    pub fn load(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("Can not read '{}'", path.display()))?;
+
+        toml::from_str(&text)
+            .with_context(|| format!("Invalid palette '{}'", path.display()))
+    }
+
+    pub fn color(&self, cell: &Cell) -> Rgb {
+        if let Some(&rgb) = self.symbols.get(&cell.symbol) {
+            return rgb;
+        }
+        if cell.symbol == '.' || cell.symbol == ' ' {
+            return self.background;
+        }
+
+        let color = match cell.color {
+            Some(color) => self
+                .colors
+                .get(&format!("{:?}", color).to_lowercase())
+                .copied()
+                .unwrap_or_else(|| ansi_color(color, self.foreground)),
+            None => self.foreground,
+        };
+
+        match cell.symbol.to_digit(10) {
+            Some(digit) if !cell.bold => {
+                color.mix(self.background, (digit + 1) as f32 / 10.0)
+            }
+            _ => color,
+        }
+    }
+
+    /// Image of `frame` with a square of `scale` pixels for every cell.
+    pub fn rasterize(&self, frame: &Frame, scale: usize) -> anyhow::Result<Image> {
+        let rows = match frame {
+            Frame::Grid(rows) => rows,
+            Frame::Dot(_) => bail!("Graph frames can not be rendered as images."),
+        };
+        ensure!(scale > 0, "The scale of an image has to be positive.");
+
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut image =
+            Image::new(columns * scale, rows.len() * scale, self.background);
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                let color = self.color(cell);
+                for py in y * scale..(y + 1) * scale {
+                    image.pixels[py * image.width + x * scale..][..scale]
+                        .fill(color);
+                }
+            }
+        }
+
+        Ok(image)
+    }
+}
+
+/// Colors of the default terminal theme of VS Code.
+fn ansi_color(color: AnsiColors, default: Rgb) -> Rgb {
+    match color {
+        AnsiColors::Black => Rgb(0x00, 0x00, 0x00),
+        AnsiColors::Red => Rgb(0xcd, 0x31, 0x31),
+        AnsiColors::Green => Rgb(0x0d, 0xbc, 0x79),
+        AnsiColors::Yellow => Rgb(0xe5, 0xe5, 0x10),
+        AnsiColors::Blue => Rgb(0x24, 0x72, 0xc8),
+        AnsiColors::Magenta => Rgb(0xbc, 0x3f, 0xbc),
+        AnsiColors::Cyan => Rgb(0x11, 0xa8, 0xcd),
+        AnsiColors::White => Rgb(0xe5, 0xe5, 0xe5),
+        AnsiColors::Default => default,
+        AnsiColors::BrightBlack => Rgb(0x66, 0x66, 0x66),
+        AnsiColors::BrightRed => Rgb(0xf1, 0x4c, 0x4c),
+        AnsiColors::BrightGreen => Rgb(0x23, 0xd1, 0x8b),
+        AnsiColors::BrightYellow => Rgb(0xf5, 0xf5, 0x43),
+        AnsiColors::BrightBlue => Rgb(0x3b, 0x8e, 0xea),
+        AnsiColors::BrightMagenta => Rgb(0xd6, 0x70, 0xd6),
+        AnsiColors::BrightCyan => Rgb(0x29, 0xb8, 0xdb),
+        AnsiColors::BrightWhite => Rgb(0xff, 0xff, 0xff),
+    }
+}
+
+/// Pixels of an image, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, color: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![color; width * height],
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Rgb]> {
+        // Chunks of an empty image would panic.
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    /// The image extended to the right and bottom with `color`.
+    fn padded(&self, width: usize, height: usize, color: Rgb) -> Image {
+        let mut image = Image::new(width, height, color);
+        for (y, row) in self.rows().enumerate() {
+            image.pixels[y * width..][..row.len()].copy_from_slice(row);
+        }
+
+        image
+    }
+
+    pub fn to_png(&self) -> anyhow::Result<Vec<u8>> {
+        png::encode(self)
+    }
+}
+
+/// Image format of exported frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// A PNG file for every frame.
+    Png,
+    /// A single animated GIF.
+    Gif,
+}
+
+impl FromStr for ImageFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "png" => Ok(ImageFormat::Png),
+            "gif" => Ok(ImageFormat::Gif),
+            _ => Err(anyhow!("{} is an invalid image format.", s)),
+        }
+    }
+}
+
+/// How frames are turned into images.
+#[derive(Debug, Clone)]
+pub struct ExportConfig {
+    pub palette: Palette,
+    /// Pixels per cell in both directions.
+    pub scale: usize,
+    /// Milliseconds between the frames of an animation.
+    pub interval: u32,
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        Self {
+            palette: Palette::default(),
+            scale: 4,
+            interval: 100,
+        }
+    }
+}
+
+/// Writes frames as images into a directory.
+///
+/// PNG frames are written as `frame_NNNN.png` right away, GIF frames are
+/// collected and written as `animation.gif` when the canvas is finished.
+pub struct ImageDirectory {
+    path: PathBuf,
+    format: ImageFormat,
+    config: ExportConfig,
+    frames: Vec<Image>,
+    written: usize,
+}
+
+impl ImageDirectory {
+    /// Creates the directory if it does not exist yet.
+    pub fn create(
+        path: impl Into<PathBuf>,
+        format: ImageFormat,
+        config: ExportConfig,
+    ) -> anyhow::Result<Self> {
+        let path = path.into();
+        fs::create_dir_all(&path)
+            .with_context(|| format!("Can not create '{}'", path.display()))?;
+
+        Ok(Self {
+            path,
+            format,
+            config,
+            frames: Vec::new(),
+            written: 0,
+        })
+    }
+
+    fn write(&self, name: &str, data: &[u8]) -> anyhow::Result<()> {
+        let file = self.path.join(name);
+        fs::write(&file, data)
+            .with_context(|| format!("Can not write '{}'", file.display()))
+    }
+}
+
+impl Canvas for ImageDirectory {
+    fn draw(&mut self, frame: Frame) -> anyhow::Result<()> {
+        let image = self.config.palette.rasterize(&frame, self.config.scale)?;
+
+        match self.format {
+            ImageFormat::Png => {
+                self.write(
+                    &format!("frame_{:04}.png", self.written),
+                    &image.to_png()?,
+                )?;
+                self.written += 1;
+            }
+            ImageFormat::Gif => self.frames.push(image),
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self) -> anyhow::Result<()> {
+        if self.format != ImageFormat::Gif || self.frames.is_empty() {
+            return Ok(());
+        }
+
+        // Frames can change their size, e.g. when an image grows, so all of them
+        // are padded to the largest one.
+        let width = self.frames.iter().map(|i| i.width).max().unwrap_or(0);
+        let height = self.frames.iter().map(|i| i.height).max().unwrap_or(0);
+        let background = self.config.palette.background;
+        let frames: Vec<_> = self
+            .frames
+            .drain(..)
+            .map(|image| image.padded(width, height, background))
+            .collect();
+
+        let gif = gif::encode(&frames, self.config.interval)?;
+        self.write("animation.gif", &gif)
+    }
+}
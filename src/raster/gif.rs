@@ -0,0 +1,77 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use ::gif::{DisposalMethod, Encoder, Frame, Repeat};
+use anyhow::{anyhow, ensure};
+
+use super::{Image, Rgb};
+
+/// Encodes `frames`, which all have the same size, as a looping animation with
+/// `interval` milliseconds between the frames.
+///
+/// Every frame has its own color table, so it may use up to 256 colors.
+pub fn encode(frames: &[Image], interval: u32) -> anyhow::Result<Vec<u8>> {
+    let first = frames
+        .first()
+        .ok_or(anyhow!("An animation needs at least one frame."))?;
+    let width = u16::try_from(first.width)
+        .map_err(|_| anyhow!("The frames are too wide for a GIF."))?;
+    let height = u16::try_from(first.height)
+        .map_err(|_| anyhow!("The frames are too high for a GIF."))?;
+    ensure!(
+        width > 0 && height > 0,
+        "An empty image can not be written as GIF."
+    );
+
+    let mut gif = Vec::new();
+    let mut encoder = Encoder::new(&mut gif, width, height, &[])?;
+    encoder.set_repeat(Repeat::Infinite)?;
+
+    let delay = u16::try_from((interval + 5) / 10).unwrap_or(u16::MAX);
+    for (number, frame) in frames.iter().enumerate() {
+        ensure!(
+            frame.width == first.width && frame.height == first.height,
+            "Frame {} has a different size than the first frame.",
+            number
+        );
+
+        let (colors, indices) = index_colors(frame)
+            .ok_or(anyhow!("Frame {} has more than 256 colors.", number))?;
+        let palette = colors.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect();
+
+        // The frame is kept when the next one is drawn over it.
+        encoder.write_frame(&Frame {
+            width,
+            height,
+            delay,
+            dispose: DisposalMethod::Keep,
+            palette: Some(palette),
+            buffer: Cow::Owned(indices),
+            ..Frame::default()
+        })?;
+    }
+
+    // The trailer is written when the encoder is done.
+    encoder.into_inner()?;
+    Ok(gif)
+}
+
+/// The colors of `image` and the index of the color of every pixel, if there are
+/// at most 256 colors.
+fn index_colors(image: &Image) -> Option<(Vec<Rgb>, Vec<u8>)> {
+    let mut colors = Vec::new();
+    let mut lookup = HashMap::new();
+
+    let indices = image
+        .pixels
+        .iter()
+        .map(|&pixel| {
+            let index = *lookup.entry(pixel).or_insert_with(|| {
+                colors.push(pixel);
+                colors.len() - 1
+            });
+            u8::try_from(index).ok()
+        })
+        .collect::<Option<_>>()?;
+
+    Some((colors, indices))
+}
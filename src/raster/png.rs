@@ -0,0 +1,39 @@
+use ::png::{BitDepth, ColorType, Encoder};
+use anyhow::{bail, ensure};
+
+use super::Image;
+
+/// Encodes `image` as an 8 bit RGB PNG.
+pub fn encode(image: &Image) -> anyhow::Result<Vec<u8>> {
+    ensure!(
+        image.width > 0 && image.height > 0,
+        "An empty image can not be written as PNG."
+    );
+    let width = u32::try_from(image.width)
+        .ok()
+        .filter(|&w| w <= i32::MAX as u32);
+    let height = u32::try_from(image.height)
+        .ok()
+        .filter(|&h| h <= i32::MAX as u32);
+    let (width, height) = match (width, height) {
+        (Some(width), Some(height)) => (width, height),
+        _ => bail!("The image is too large for a PNG."),
+    };
+
+    let data: Vec<u8> = image
+        .pixels
+        .iter()
+        .flat_map(|pixel| [pixel.0, pixel.1, pixel.2])
+        .collect();
+
+    let mut png = Vec::new();
+    let mut encoder = Encoder::new(&mut png, width, height);
+    encoder.set_color(ColorType::Rgb);
+    encoder.set_depth(BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&data)?;
+    writer.finish()?;
+
+    Ok(png)
+}
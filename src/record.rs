@@ -0,0 +1,132 @@
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    cache::{CacheKey, Cached},
+    runner::Report,
+    solver, Answer, Day, Params, Puzzle,
+};
+
+/// Machine-readable outcome of solving a puzzle, printed by `--format json` as
+/// a line of JSON. All durations are in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: Day,
+    pub puzzle: Puzzle,
+    /// Input file, `None` for stdin or inline text.
+    pub input: Option<PathBuf>,
+    /// Hex encoded SHA-256 of the input, `None` if it could not be read.
+    pub input_sha256: Option<String>,
+    pub answer: Option<Answer>,
+    /// The error with its causes if the puzzle failed.
+    pub error: Option<String>,
+    /// Reading and parsing the input, `None` if the puzzle was stopped before.
+    pub parse_ns: Option<u64>,
+    /// Parsing the input and solving the puzzle.
+    pub time_ns: u64,
+    /// The answer and times are from the cache of an earlier run.
+    pub cached: bool,
+}
+
+/// Hex encoded SHA-256 of `text`.
+pub fn sha256(text: &str) -> String {
+    Sha256::digest(text.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl Record {
+    /// Parses `text` and solves `puzzle` with `params`, timing both.
+    pub fn solve(
+        day: Day,
+        puzzle: Puzzle,
+        input: Option<&Path>,
+        text: &str,
+        params: &Params,
+    ) -> Self {
+        let start = Instant::now();
+        let parsed = solver(day)
+            .ok_or(anyhow!("There is no solution for day {}.", day))
+            .and_then(|solver| Ok((solver, solver.parse(text)?)));
+        let parse_time = start.elapsed();
+        let answer = parsed
+            .and_then(|(solver, parsed)| solver.solve_with(&parsed, puzzle, params));
+        let time = start.elapsed();
+
+        Self {
+            day,
+            puzzle,
+            input: input.map(Path::to_path_buf),
+            input_sha256: Some(sha256(text)),
+            error: answer.as_ref().err().map(|e| format!("{:#}", e)),
+            answer: answer.ok(),
+            parse_ns: Some(nanos(parse_time)),
+            time_ns: nanos(time),
+            cached: false,
+        }
+    }
+
+    /// The record of a puzzle run on the input file `input`.
+    pub fn from_report(report: &Report, input: &Path) -> Self {
+        Self {
+            day: report.day,
+            puzzle: report.puzzle,
+            input: Some(input.to_path_buf()),
+            input_sha256: report.input_sha256.clone(),
+            answer: report.answer.as_ref().ok().cloned(),
+            error: report.answer.as_ref().err().map(|e| format!("{:#}", e)),
+            parse_ns: report.parse_time.map(nanos),
+            time_ns: nanos(report.time),
+            cached: report.cached,
+        }
+    }
+
+    /// The record of an answer from the cache, with the times of its first run.
+    pub fn from_cache(
+        input: Option<&Path>,
+        key: &CacheKey,
+        cached: &Cached,
+    ) -> Self {
+        Self {
+            day: key.day,
+            puzzle: key.puzzle,
+            input: input.map(Path::to_path_buf),
+            input_sha256: Some(key.input_sha256.clone()),
+            answer: Some(cached.answer.clone()),
+            error: None,
+            parse_ns: cached.parse_time.map(nanos),
+            time_ns: nanos(cached.time),
+            cached: true,
+        }
+    }
+
+    /// The answer of the record to cache, unless it failed or is from the cache.
+    pub fn to_cache(&self) -> Option<Cached> {
+        match &self.answer {
+            Some(answer) if !self.cached => Some(Cached {
+                answer: answer.clone(),
+                parse_time: self.parse_ns.map(Duration::from_nanos),
+                time: Duration::from_nanos(self.time_ns),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Writes the record as a single line of JSON.
+    pub fn write(&self, mut out: impl Write) -> io::Result<()> {
+        serde_json::to_writer(&mut out, self)?;
+        writeln!(out)
+    }
+}
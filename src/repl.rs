@@ -0,0 +1,151 @@
+use std::io::{BufRead, Write};
+
+use anyhow::{anyhow, bail, Context};
+
+use crate::Day;
+
+/// A day specific command of an [`Inspector`], run with `query <name> <args>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Query {
+    pub name: &'static str,
+    /// Arguments as shown in the help, e.g. `<cave>`.
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+/// State built from the parsed input of a day, which the REPL shows, advances
+/// and answers queries about.
+pub trait Inspector {
+    /// Prints the current state.
+    fn show(&self, out: &mut dyn Write) -> anyhow::Result<()>;
+
+    /// Advances the state by `steps`, e.g. steps of pair insertion.
+    fn step(&mut self, _steps: usize) -> anyhow::Result<()> {
+        bail!("There are no steps to take.")
+    }
+
+    /// Goes back to the state right after parsing.
+    fn reset(&mut self);
+
+    /// The queries which [`Inspector::query`] answers.
+    fn queries(&self) -> &'static [Query] {
+        &[]
+    }
+
+    /// Answers the query `name`, which is one of [`Inspector::queries`].
+    fn query(
+        &self,
+        name: &str,
+        _args: &[&str],
+        _out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        bail!("There is no query '{}'.", name)
+    }
+}
+
+/// The argument of a query at `index`, parsed as `T`.
+pub fn arg<T>(args: &[&str], index: usize, name: &str) -> anyhow::Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let arg = args
+        .get(index)
+        .ok_or(anyhow!("Missing argument {}.", name))?;
+
+    arg.parse()
+        .with_context(|| format!("Invalid argument {} '{}'", name, arg))
+}
+
+/// Reads commands and runs them on the inspector of a day.
+pub struct Repl<'a> {
+    day: Day,
+    inspector: Box<dyn Inspector + 'a>,
+}
+
+impl<'a> Repl<'a> {
+    pub fn new(day: Day, inspector: Box<dyn Inspector + 'a>) -> Self {
+        Self { day, inspector }
+    }
+
+    /// Runs the commands of `input` line by line until it ends or a `quit`.
+    /// Failed commands are reported on `out` and don't stop the REPL.
+    pub fn run(
+        &mut self,
+        input: impl BufRead,
+        mut out: impl Write,
+        prompt: bool,
+    ) -> anyhow::Result<()> {
+        let mut lines = input.lines();
+
+        loop {
+            if prompt {
+                write!(out, "day {}> ", self.day)?;
+                out.flush()?;
+            }
+
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => break,
+            };
+
+            match self.execute(&line, &mut out) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => writeln!(out, "error: {:#}", e)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs a single command. Returns `false` if the REPL should stop.
+    pub fn execute(
+        &mut self,
+        line: &str,
+        out: &mut dyn Write,
+    ) -> anyhow::Result<bool> {
+        let words: Vec<_> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            [] => {}
+            ["quit" | "exit"] => return Ok(false),
+            ["help"] => self.help(out)?,
+            ["show"] => self.inspector.show(out)?,
+            ["step"] => self.inspector.step(1)?,
+            ["step", steps] => {
+                let steps = steps.parse().with_context(|| {
+                    format!("Invalid number of steps '{}'", steps)
+                })?;
+                self.inspector.step(steps)?;
+            }
+            ["reset"] => self.inspector.reset(),
+            ["query"] => self.help(out)?,
+            ["query", name, args @ ..] => {
+                if !self.inspector.queries().iter().any(|q| q.name == *name) {
+                    bail!("Day {} has no query '{}', see `help`.", self.day, name);
+                }
+                self.inspector.query(name, args, out)?;
+            }
+            [command, ..] => bail!("Unknown command '{}', see `help`.", command),
+        }
+
+        Ok(true)
+    }
+
+    fn help(&self, out: &mut dyn Write) -> anyhow::Result<()> {
+        writeln!(out, "show                 Print the current state")?;
+        writeln!(
+            out,
+            "step [n]             Advance the state by n steps, one by default"
+        )?;
+        writeln!(out, "reset                Go back to the parsed input")?;
+        for query in self.inspector.queries() {
+            let usage = format!("query {} {}", query.name, query.args);
+            writeln!(out, "{:<20} {}", usage.trim_end(), query.help)?;
+        }
+        writeln!(out, "quit                 Leave the REPL")?;
+
+        Ok(())
+    }
+}
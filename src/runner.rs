@@ -0,0 +1,309 @@
+use std::{
+    io::{self, Write},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, ensure, Context};
+
+pub use self::limits::{run_worker, LimitError, Limits};
+use crate::{
+    cache::Cache, inputs::Inputs, record::sha256, solvers, Answer, Day, Parsed,
+    Puzzle, Solver,
+};
+
+mod limits;
+
+/// Days which take most of the time of a full run, slowest first. The parallel
+/// runner starts them first, so they overlap with all the other days.
+const SLOW_DAYS: [u32; 3] = [23, 20, 12];
+
+/// A set of days, parsed from a list of days and ranges like `3-9,15`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(Vec<Day>);
+
+impl DaySelection {
+    pub fn all() -> Self {
+        Self(solvers().map(|s| s.day()).collect())
+    }
+
+    pub fn days(&self) -> &[Day] {
+        &self.0
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = Vec::new();
+
+        for part in s.split(',').map(str::trim) {
+            if let Some((start, end)) = part.split_once('-') {
+                let start: Day = start.trim().parse()?;
+                let end: Day = end.trim().parse()?;
+                ensure!(start.0 <= end.0, "Invalid day range '{}'.", part);
+
+                days.extend((start.0..=end.0).map(Day));
+            } else {
+                days.push(part.parse()?);
+            }
+        }
+
+        days.sort_by_key(|d| d.0);
+        days.dedup();
+
+        Ok(Self(days))
+    }
+}
+
+/// Outcome of running a single puzzle.
+pub struct Report {
+    pub day: Day,
+    pub puzzle: Puzzle,
+    pub answer: anyhow::Result<Answer>,
+    /// Wall time of parsing the input and solving the puzzle.
+    pub time: Duration,
+    /// Wall time of reading and parsing the input, unless the puzzle was stopped
+    /// before it reported it.
+    pub parse_time: Option<Duration>,
+    /// The answer and times are from the [`Cache`] of an earlier run.
+    pub cached: bool,
+    /// Hex encoded SHA-256 of the input which was solved, `None` if it could not
+    /// be read.
+    pub input_sha256: Option<String>,
+}
+
+/// Path of `res/dNN_<suffix>.txt`, the inputs the answer manifest is about.
+pub fn input_path(day: Day, suffix: &str) -> PathBuf {
+    Inputs::default().path(day, suffix)
+}
+
+/// Reads and parses the input at `path`, returns the text which was parsed
+/// unless it could not be read.
+fn parse_input(
+    solver: &dyn Solver,
+    path: &Path,
+) -> (Option<String>, anyhow::Result<Parsed>) {
+    match std::fs::read_to_string(path)
+        .with_context(|| format!("Can not read '{}'", path.display()))
+    {
+        Ok(text) => {
+            let parsed = solver.parse(&text);
+            (Some(text), parsed)
+        }
+        Err(e) => (None, Err(e)),
+    }
+}
+
+fn run_solver(solver: &dyn Solver, inputs: &Inputs, suffix: &str) -> Vec<Report> {
+    let day = solver.day();
+
+    let start = Instant::now();
+    let (text, parsed) = parse_input(solver, &inputs.path(day, suffix));
+    let parse_time = start.elapsed();
+    let input_sha256 = text.as_deref().map(sha256);
+
+    solver
+        .puzzles()
+        .iter()
+        .map(|&puzzle| {
+            let start = Instant::now();
+            let answer = match &parsed {
+                Ok(parsed) => solver.solve(parsed, puzzle),
+                Err(e) => Err(anyhow!("{:#}", e)),
+            };
+            let time = parse_time + start.elapsed();
+
+            Report {
+                day,
+                puzzle,
+                answer,
+                time,
+                parse_time: Some(parse_time),
+                cached: false,
+                input_sha256: input_sha256.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Runs every puzzle of the selected days on their input `dNN_<suffix>.txt`.
+///
+/// Failures are reported in the corresponding [`Report`] and don't stop the run.
+pub fn run_days(days: &DaySelection, inputs: &Inputs, suffix: &str) -> Vec<Report> {
+    solvers()
+        .filter(|s| days.days().contains(&s.day()))
+        .flat_map(|s| run_solver(s, inputs, suffix))
+        .collect()
+}
+
+/// How [`run_days_parallel`] runs the puzzles.
+#[derive(Debug, Clone)]
+pub struct RunConfig {
+    /// Threads running puzzles at the same time.
+    pub jobs: NonZeroUsize,
+    /// Runs every puzzle in its own process with these limits.
+    pub limits: Option<Limits>,
+    /// Reports the answers of this cache instead of solving the puzzles again.
+    pub cache: Option<Cache>,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            jobs: NonZeroUsize::MIN,
+            limits: None,
+            cache: None,
+        }
+    }
+}
+
+/// Runs the puzzles of the selected days like [`run_days`], but on up to
+/// `config.jobs` threads.
+///
+/// Every puzzle parses its own input, so the parts of a day run in parallel as
+/// well and the time of a puzzle is measured on the thread solving it. The
+/// reports are in the same order as the ones of [`run_days`].
+pub fn run_days_parallel(
+    days: &DaySelection,
+    inputs: &Inputs,
+    suffix: &str,
+    config: &RunConfig,
+) -> Vec<Report> {
+    let tasks: Vec<(&dyn Solver, Puzzle)> = solvers()
+        .filter(|s| days.days().contains(&s.day()))
+        .flat_map(|s| s.puzzles().iter().map(move |&puzzle| (s, puzzle)))
+        .collect();
+
+    // The slow days are started first, the others keep their order.
+    let mut order: Vec<usize> = (0..tasks.len()).collect();
+    order.sort_by_key(|&i| {
+        SLOW_DAYS
+            .iter()
+            .position(|&day| tasks[i].0.day().0 == day)
+            .unwrap_or(SLOW_DAYS.len())
+    });
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..config.jobs.get().min(tasks.len()) {
+            let sender = sender.clone();
+            let (tasks, order, next) = (&tasks, &order, &next);
+
+            scope.spawn(move || {
+                while let Some(&i) = order.get(next.fetch_add(1, Ordering::Relaxed))
+                {
+                    let (solver, puzzle) = tasks[i];
+                    let path = inputs.path(solver.day(), suffix);
+                    let cached = config
+                        .cache
+                        .as_ref()
+                        .and_then(|cache| cache.lookup(solver.day(), puzzle, &path));
+                    let report = match (cached, &config.limits) {
+                        (Some(report), _) => report,
+                        (None, Some(limits)) => {
+                            limits.run(solver.day(), puzzle, &path)
+                        }
+                        (None, None) => run_puzzle(solver, puzzle, &path),
+                    };
+
+                    // The receiver outlives the scope, so sending can not fail.
+                    let _ = sender.send((i, report));
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut reports: Vec<_> = receiver.into_iter().collect();
+    reports.sort_by_key(|&(i, _)| i);
+    reports.into_iter().map(|(_, report)| report).collect()
+}
+
+fn run_puzzle(solver: &dyn Solver, puzzle: Puzzle, path: &Path) -> Report {
+    let start = Instant::now();
+    let (text, parsed) = parse_input(solver, path);
+    let parse_time = start.elapsed();
+    let answer = parsed.and_then(|parsed| solver.solve(&parsed, puzzle));
+    let time = start.elapsed();
+
+    Report {
+        day: solver.day(),
+        puzzle,
+        answer,
+        time,
+        parse_time: Some(parse_time),
+        cached: false,
+        input_sha256: text.as_deref().map(sha256),
+    }
+}
+
+pub fn print_table(reports: &[Report], mut out: impl Write) -> io::Result<()> {
+    let answers: Vec<_> = reports
+        .iter()
+        .map(|r| match &r.answer {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {:#}", e),
+        })
+        .collect();
+
+    let width = answers
+        .iter()
+        .flat_map(|a| a.lines())
+        .map(|l| l.chars().count())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap();
+
+    writeln!(
+        out,
+        "{:>3}  {:<6}  {:<width$}  Time",
+        "Day",
+        "Part",
+        "Answer",
+        width = width
+    )?;
+
+    for (report, answer) in reports.iter().zip(&answers) {
+        let mut lines = answer.lines();
+        let first_line = lines.next().unwrap_or("");
+
+        writeln!(
+            out,
+            "{:>3}  {:<6}  {:<width$}  {:.2?}{}",
+            report.day.0,
+            report.puzzle.to_string(),
+            first_line,
+            report.time,
+            if report.cached { " (cached)" } else { "" },
+            width = width
+        )?;
+
+        for line in lines {
+            writeln!(out, "{:>3}  {:<6}  {}", "", "", line)?;
+        }
+    }
+
+    let failed = reports.iter().filter(|r| r.answer.is_err()).count();
+    let total_time: Duration = reports.iter().map(|r| r.time).sum();
+
+    writeln!(
+        out,
+        "\n{} puzzles, {} failed, {:.2?} total",
+        reports.len(),
+        failed,
+        total_time
+    )?;
+
+    Ok(())
+}
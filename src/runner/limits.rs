@@ -0,0 +1,107 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+#[cfg(all(unix, feature = "cli"))]
+use self::unix::spawn_worker;
+use super::{run_puzzle, Report};
+use crate::{solver, Answer, Day, Puzzle};
+
+#[cfg(all(unix, feature = "cli"))]
+mod unix;
+
+/// Limits of a puzzle which is solved in its own process, so it can be stopped
+/// when it runs too long or allocates too much.
+#[derive(Debug, Clone)]
+pub struct Limits {
+    /// Executable with the hidden `worker` command, usually the running `aoc2021`.
+    pub program: PathBuf,
+    /// Wall time of reading the input, parsing it and solving the puzzle.
+    pub timeout: Option<Duration>,
+    /// Size of the address space of the process in bytes.
+    pub memory: Option<u64>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LimitError {
+    #[error("Timed out after {0:.2?}.")]
+    TimedOut(Duration),
+    #[error("Out of memory, the limit is {} MiB.", .0 / (1024 * 1024))]
+    OutOfMemory(u64),
+    #[error("Limits are only supported on unix with the `cli` feature.")]
+    Unsupported,
+}
+
+/// Result of a puzzle, as it is passed from the worker to the runner.
+#[derive(Serialize, Deserialize)]
+struct WorkerReport {
+    answer: Result<Answer, String>,
+    time: Duration,
+    parse_time: Option<Duration>,
+    input_sha256: Option<String>,
+}
+
+/// Solves a single puzzle like a worker of [`Limits`] and writes its report as a
+/// line of JSON.
+pub fn run_worker(
+    day: Day,
+    puzzle: Puzzle,
+    path: &Path,
+    mut out: impl Write,
+) -> anyhow::Result<()> {
+    let solver = solver(day).ok_or(anyhow!("Day {} has no solver.", day))?;
+    let report = run_puzzle(solver, puzzle, path);
+
+    let report = WorkerReport {
+        answer: report.answer.map_err(|e| format!("{:#}", e)),
+        time: report.time,
+        parse_time: report.parse_time,
+        input_sha256: report.input_sha256,
+    };
+    serde_json::to_writer(&mut out, &report)?;
+    writeln!(out)?;
+
+    Ok(())
+}
+
+impl Limits {
+    pub(super) fn run(&self, day: Day, puzzle: Puzzle, path: &Path) -> Report {
+        let start = Instant::now();
+        match spawn_worker(self, day, puzzle, path) {
+            Ok(report) => Report {
+                day,
+                puzzle,
+                answer: report.answer.map_err(|e| anyhow!(e)),
+                time: report.time,
+                parse_time: report.parse_time,
+                cached: false,
+                input_sha256: report.input_sha256,
+            },
+            Err(e) => Report {
+                day,
+                puzzle,
+                answer: Err(e),
+                time: start.elapsed(),
+                parse_time: None,
+                cached: false,
+                input_sha256: None,
+            },
+        }
+    }
+}
+
+/// Workers need the `worker` command of the binary and `setrlimit`.
+#[cfg(not(all(unix, feature = "cli")))]
+fn spawn_worker(
+    _limits: &Limits,
+    _day: Day,
+    _puzzle: Puzzle,
+    _path: &Path,
+) -> anyhow::Result<WorkerReport> {
+    Err(LimitError::Unsupported.into())
+}
@@ -0,0 +1,117 @@
+use std::{
+    io::{self, Read},
+    os::unix::process::{CommandExt, ExitStatusExt},
+    path::Path,
+    process::{Child, Command, Stdio},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, ensure, Context};
+
+use super::{LimitError, Limits, WorkerReport};
+use crate::{Day, Puzzle};
+
+/// How often a worker process is checked while it runs.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Runs a puzzle in a worker process of the binary, with the memory limited by
+/// `setrlimit` and killed on a timeout.
+pub(super) fn spawn_worker(
+    limits: &Limits,
+    day: Day,
+    puzzle: Puzzle,
+    path: &Path,
+) -> anyhow::Result<WorkerReport> {
+    let mut command = Command::new(&limits.program);
+    command
+        .arg("worker")
+        .args(["--day", &day.to_string(), "--puzzle", &puzzle.to_string()])
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if let Some(bytes) = limits.memory {
+        let limit = libc::rlimit {
+            rlim_cur: bytes as libc::rlim_t,
+            rlim_max: bytes as libc::rlim_t,
+        };
+
+        // SAFETY: `setrlimit` is async signal safe and only limits the child.
+        unsafe {
+            command.pre_exec(move || {
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+    }
+
+    let mut child = command
+        .spawn()
+        .with_context(|| format!("Can not run '{}'", limits.program.display()))?;
+
+    // The pipes are drained while the worker runs, so it never blocks on them.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+
+        if let Some(timeout) = limits.timeout.filter(|&t| start.elapsed() >= t) {
+            kill(&mut child)?;
+            return Err(LimitError::TimedOut(timeout).into());
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    };
+
+    let stdout = join(stdout);
+    let stderr = join(stderr);
+
+    // An allocation beyond the limit fails, which aborts the worker.
+    if let Some(bytes) = limits.memory {
+        if status.signal() == Some(libc::SIGABRT) {
+            return Err(LimitError::OutOfMemory(bytes).into());
+        }
+    }
+
+    ensure!(
+        status.success(),
+        "The worker failed with {}: {}",
+        status,
+        stderr.trim()
+    );
+
+    let line = stdout.lines().rev().find(|l| !l.trim().is_empty());
+    let line = line.ok_or(anyhow!("The worker reported nothing."))?;
+    serde_json::from_str(line)
+        .with_context(|| format!("Invalid report of the worker '{}'", line))
+}
+
+fn kill(child: &mut Child) -> io::Result<()> {
+    child.kill()?;
+    child.wait()?;
+
+    Ok(())
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        if let Some(mut pipe) = pipe {
+            // A worker which is killed leaves partial output, which is fine.
+            let _ = pipe.read_to_string(&mut text);
+        }
+        text
+    })
+}
+
+fn join(handle: JoinHandle<String>) -> String {
+    handle.join().unwrap_or_default()
+}
@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+#[cfg(feature = "cli")]
+pub use self::terminal::play;
+use crate::visualize::{Canvas, Frame};
+
+#[cfg(feature = "cli")]
+mod terminal;
+
+/// Simulation which advances in discrete steps, e.g. a cellular automaton on a grid.
+pub trait Simulation {
+    /// Advances the simulation by a single step.
+    /// Returns `false` if the simulation has already finished.
+    fn step(&mut self) -> bool;
+
+    /// Picture of the current state.
+    fn frame(&self) -> Frame;
+}
+
+/// Draws the initial state and the state after every step.
+pub fn record(
+    simulation: &mut dyn Simulation,
+    canvas: &mut dyn Canvas,
+) -> anyhow::Result<()> {
+    canvas.draw(simulation.frame())?;
+    while simulation.step() {
+        canvas.draw(simulation.frame())?;
+    }
+
+    Ok(())
+}
+
+const MIN_DELAY: Duration = Duration::from_millis(10);
+const MAX_DELAY: Duration = Duration::from_millis(2560);
+
+/// Plays a simulation back and forth.
+///
+/// The frames of all steps which were simulated so far are kept, so going back
+/// does not need to run the simulation again.
+pub struct Player<'a> {
+    simulation: Box<dyn Simulation + 'a>,
+    frames: Vec<Frame>,
+    finished: bool,
+    step: usize,
+    playing: bool,
+    delay: Duration,
+}
+
+impl<'a> Player<'a> {
+    pub fn new(simulation: Box<dyn Simulation + 'a>) -> Self {
+        let frames = vec![simulation.frame()];
+
+        Self {
+            simulation,
+            frames,
+            finished: false,
+            step: 0,
+            playing: false,
+            delay: Duration::from_millis(160),
+        }
+    }
+
+    /// Step of the frame which is shown, the initial state is step 0.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// The last step, once the simulation has finished.
+    pub fn last_step(&self) -> Option<usize> {
+        self.finished.then(|| self.frames.len() - 1)
+    }
+
+    pub fn frame(&self) -> &Frame {
+        &self.frames[self.step]
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Time between two frames while playing.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Shows the next step, returns `false` if there is none.
+    pub fn forward(&mut self) -> bool {
+        if self.step + 1 == self.frames.len() {
+            if self.finished || !self.simulation.step() {
+                self.finished = true;
+                return false;
+            }
+            self.frames.push(self.simulation.frame());
+        }
+
+        self.step += 1;
+        true
+    }
+
+    /// Shows the previous step, returns `false` at the initial state.
+    pub fn back(&mut self) -> bool {
+        if self.step == 0 {
+            return false;
+        }
+
+        self.step -= 1;
+        true
+    }
+
+    /// Shows `step`, or the last step if the simulation finishes before.
+    pub fn jump(&mut self, step: usize) {
+        if step < self.frames.len() {
+            self.step = step;
+        } else {
+            self.step = self.frames.len() - 1;
+            while self.step < step && self.forward() {}
+        }
+    }
+
+    pub fn toggle_playing(&mut self) {
+        self.playing = !self.playing;
+    }
+
+    pub fn faster(&mut self) {
+        self.delay = (self.delay / 2).max(MIN_DELAY);
+    }
+
+    pub fn slower(&mut self) {
+        self.delay = (self.delay * 2).min(MAX_DELAY);
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use anyhow::Context;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    queue, terminal,
+};
+
+use super::{Player, Simulation};
+
+impl Player<'_> {
+    /// Advances while playing, pauses at the end of the simulation.
+    fn tick(&mut self) {
+        if self.playing && !self.forward() {
+            self.playing = false;
+        }
+    }
+
+    fn apply(&mut self, key: Key) {
+        match key {
+            Key::Char(' ') => self.toggle_playing(),
+            Key::Right | Key::Char('l') | Key::Char('.') => {
+                self.playing = false;
+                self.forward();
+            }
+            Key::Left | Key::Char('h') | Key::Char(',') => {
+                self.playing = false;
+                self.back();
+            }
+            Key::Char('+') | Key::Up => self.faster(),
+            Key::Char('-') | Key::Down => self.slower(),
+            Key::Home => self.jump(0),
+            _ => {}
+        }
+    }
+
+    fn status(&self) -> String {
+        let last = self
+            .last_step()
+            .map_or_else(|| "?".to_string(), |last| last.to_string());
+        let state = if self.playing { "playing" } else { "paused" };
+
+        format!(
+            "Step {}/{}, {}, {} ms per step",
+            self.step,
+            last,
+            state,
+            self.delay.as_millis()
+        )
+    }
+}
+
+const HELP: &str =
+    "space: play/pause, ←/→: step, +/-: speed, g: jump to step, home: restart, q: quit";
+
+/// Plays `simulation` in the terminal until the user quits.
+///
+/// Keys are read from the controlling terminal, so the puzzle input can still be
+/// piped in on stdin.
+pub fn play(simulation: Box<dyn Simulation + '_>) -> anyhow::Result<()> {
+    let _raw = RawMode::enable()?;
+    let mut out = io::stdout();
+
+    queue!(out, cursor::Hide)?;
+    let result = run_player(Player::new(simulation), &mut out);
+    queue!(out, cursor::Show)?;
+    out.flush()?;
+
+    result
+}
+
+fn run_player(mut player: Player, out: &mut impl Write) -> anyhow::Result<()> {
+    // Digits of the step to jump to, while they are entered.
+    let mut jump: Option<String> = None;
+
+    loop {
+        let prompt = match &jump {
+            Some(digits) => format!("Jump to step: {}", digits),
+            None => HELP.to_string(),
+        };
+        // Raw mode does not return the carriage at the end of a line.
+        let screen = format!(
+            "{}\n{}\n{}\n",
+            player.frame().to_colored_text(),
+            player.status(),
+            prompt
+        );
+        queue!(
+            out,
+            cursor::MoveTo(0, 0),
+            terminal::Clear(terminal::ClearType::All)
+        )?;
+        write!(out, "{}", screen.replace('\n', "\r\n"))?;
+        out.flush()?;
+
+        let timeout = player.is_playing().then(|| player.delay());
+        let key = match read_key(timeout)? {
+            Some(key) => key,
+            None => {
+                player.tick();
+                continue;
+            }
+        };
+
+        match (&mut jump, key) {
+            (_, Key::Char('q')) | (_, Key::Interrupt) | (None, Key::Escape) => {
+                return Ok(())
+            }
+            (Some(digits), Key::Char(c)) if c.is_ascii_digit() => digits.push(c),
+            (Some(digits), Key::Backspace) => {
+                digits.pop();
+            }
+            (Some(digits), Key::Enter) => {
+                if let Ok(step) = digits.parse() {
+                    player.jump(step);
+                }
+                jump = None;
+            }
+            (Some(_), _) => jump = None,
+            (None, Key::Char('g')) => jump = Some(String::new()),
+            (None, key) => player.apply(key),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Char(char),
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    Enter,
+    Backspace,
+    Escape,
+    Interrupt,
+    Unknown,
+}
+
+impl From<KeyEvent> for Key {
+    fn from(event: KeyEvent) -> Self {
+        match event.code {
+            KeyCode::Char('c')
+                if event.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                Key::Interrupt
+            }
+            KeyCode::Char(c) => Key::Char(c),
+            KeyCode::Left => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::Up => Key::Up,
+            KeyCode::Down => Key::Down,
+            KeyCode::Home => Key::Home,
+            KeyCode::Enter => Key::Enter,
+            KeyCode::Backspace => Key::Backspace,
+            KeyCode::Esc => Key::Escape,
+            _ => Key::Unknown,
+        }
+    }
+}
+
+/// Waits at most `timeout` for a key, forever without a timeout. Other events,
+/// like a resize of the terminal, are skipped.
+fn read_key(timeout: Option<Duration>) -> anyhow::Result<Option<Key>> {
+    let error = "Can not read from the terminal";
+
+    loop {
+        if let Some(timeout) = timeout {
+            if !event::poll(timeout).context(error)? {
+                return Ok(None);
+            }
+        }
+
+        if let Event::Key(key) = event::read().context(error)? {
+            return Ok(Some(key.into()));
+        }
+    }
+}
+
+/// Raw mode of the terminal, which is restored when dropped.
+///
+/// Signals are disabled as well, so Ctrl-C is read as a key and the terminal is
+/// restored on the way out.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> anyhow::Result<Self> {
+        terminal::enable_raw_mode().context("Can not configure the terminal")?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
@@ -0,0 +1,226 @@
+use std::any::Any;
+
+use anyhow::{anyhow, bail};
+use tracing::info_span;
+
+use crate::{
+    repl::Inspector, simulation::Simulation, solvers::SOLVERS, visualize::Canvas,
+    Answer, Day, Param, Params, Puzzle,
+};
+
+/// Solution for the puzzles of a single day.
+///
+/// The input is parsed once and both parts are solved on the parsed value.
+pub trait Solution {
+    const DAY: Day;
+
+    /// Puzzles which are actually solved by this day.
+    const PUZZLES: &'static [Puzzle] = &[Puzzle::First, Puzzle::Second];
+
+    /// Constants of the puzzles which can be changed with [`Params`].
+    const PARAMS: &'static [Param] = &[];
+
+    type Input: Send + Sync + 'static;
+    type First: Into<Answer>;
+    type Second: Into<Answer>;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::First>;
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Second>;
+
+    /// Solves the first puzzle with the values of [`Self::PARAMS`], which are
+    /// ignored unless the solution declares any.
+    fn part1_with(
+        input: &Self::Input,
+        _params: &Params,
+    ) -> anyhow::Result<Self::First> {
+        Self::part1(input)
+    }
+
+    /// Solves the second puzzle with the values of [`Self::PARAMS`].
+    fn part2_with(
+        input: &Self::Input,
+        _params: &Params,
+    ) -> anyhow::Result<Self::Second> {
+        Self::part2(input)
+    }
+
+    /// Draws how the puzzle is solved, e.g. every step of a simulation.
+    fn visualize(
+        _input: &Self::Input,
+        _puzzle: Puzzle,
+        _canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        bail!("Day {} has no visualization.", Self::DAY)
+    }
+
+    /// The puzzle as a step by step simulation, which can be played back.
+    fn simulation(
+        _input: &Self::Input,
+        _puzzle: Puzzle,
+    ) -> anyhow::Result<Box<dyn Simulation + '_>> {
+        bail!("Day {} has no simulation.", Self::DAY)
+    }
+
+    /// Commands to explore the parsed input in the REPL.
+    fn inspector(_input: &Self::Input) -> anyhow::Result<Box<dyn Inspector + '_>> {
+        bail!("Day {} can not be inspected.", Self::DAY)
+    }
+}
+
+/// Parsed input of a day, with its concrete type erased.
+pub struct Parsed {
+    day: Day,
+    input: Box<dyn Any + Send + Sync>,
+}
+
+/// Object safe version of [`Solution`], which is implemented for every solution.
+pub trait Solver: Sync {
+    fn day(&self) -> Day;
+
+    fn puzzles(&self) -> &'static [Puzzle];
+
+    fn params(&self) -> &'static [Param];
+
+    fn parse(&self, text: &str) -> anyhow::Result<Parsed>;
+
+    /// Solves `puzzle` with the default parameters.
+    fn solve(&self, input: &Parsed, puzzle: Puzzle) -> anyhow::Result<Answer> {
+        self.solve_with(input, puzzle, &Params::default())
+    }
+
+    /// Solves `puzzle` with `params` on top of the defaults.
+    fn solve_with(
+        &self,
+        input: &Parsed,
+        puzzle: Puzzle,
+        params: &Params,
+    ) -> anyhow::Result<Answer>;
+
+    fn visualize(
+        &self,
+        input: &Parsed,
+        puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()>;
+
+    fn simulation<'a>(
+        &self,
+        input: &'a Parsed,
+        puzzle: Puzzle,
+    ) -> anyhow::Result<Box<dyn Simulation + 'a>>;
+
+    fn inspector<'a>(
+        &self,
+        input: &'a Parsed,
+    ) -> anyhow::Result<Box<dyn Inspector + 'a>>;
+
+    fn has_puzzle(&self, puzzle: Puzzle) -> bool {
+        self.puzzles().contains(&puzzle)
+    }
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn day(&self) -> Day {
+        S::DAY
+    }
+
+    fn puzzles(&self) -> &'static [Puzzle] {
+        S::PUZZLES
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn parse(&self, text: &str) -> anyhow::Result<Parsed> {
+        let _span = info_span!("parse", day = %S::DAY).entered();
+
+        Ok(Parsed {
+            day: S::DAY,
+            input: Box::new(S::parse(text)?),
+        })
+    }
+
+    fn solve_with(
+        &self,
+        input: &Parsed,
+        puzzle: Puzzle,
+        params: &Params,
+    ) -> anyhow::Result<Answer> {
+        let parsed = input_for::<S>(input, puzzle)?;
+        let params = params.resolve(S::DAY, S::PARAMS, puzzle)?;
+        let _span = info_span!("solve", day = %S::DAY, %puzzle).entered();
+
+        let result = match puzzle {
+            Puzzle::First => S::part1_with(parsed, &params)?.into(),
+            Puzzle::Second => S::part2_with(parsed, &params)?.into(),
+        };
+
+        Ok(result)
+    }
+
+    fn visualize(
+        &self,
+        input: &Parsed,
+        puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        let parsed = input_for::<S>(input, puzzle)?;
+
+        S::visualize(parsed, puzzle, canvas)
+    }
+
+    fn simulation<'a>(
+        &self,
+        input: &'a Parsed,
+        puzzle: Puzzle,
+    ) -> anyhow::Result<Box<dyn Simulation + 'a>> {
+        let parsed = input_for::<S>(input, puzzle)?;
+
+        S::simulation(parsed, puzzle)
+    }
+
+    fn inspector<'a>(
+        &self,
+        input: &'a Parsed,
+    ) -> anyhow::Result<Box<dyn Inspector + 'a>> {
+        S::inspector(downcast::<S>(input)?)
+    }
+}
+
+/// The parsed input of `S`, checking that `S` actually solves `puzzle`.
+fn input_for<S: Solution>(
+    input: &Parsed,
+    puzzle: Puzzle,
+) -> anyhow::Result<&S::Input> {
+    if !S::PUZZLES.contains(&puzzle) {
+        bail!(
+            "Day {} has no {} puzzle, available: {}.",
+            S::DAY,
+            puzzle,
+            itertools::join(S::PUZZLES, ", ")
+        );
+    }
+
+    downcast::<S>(input)
+}
+
+fn downcast<S: Solution>(input: &Parsed) -> anyhow::Result<&S::Input> {
+    input.input.downcast_ref::<S::Input>().ok_or(anyhow!(
+        "Input of day {} can not be solved by day {}.",
+        input.day,
+        S::DAY
+    ))
+}
+
+/// All registered solvers, ordered by day.
+pub fn solvers() -> impl Iterator<Item = &'static dyn Solver> {
+    SOLVERS.iter().copied()
+}
+
+pub fn solver(day: Day) -> Option<&'static dyn Solver> {
+    solvers().find(|s| s.day() == day)
+}
@@ -0,0 +1,44 @@
+use crate::{parse, Day, Solution};
+
+fn parse(input: &str) -> anyhow::Result<Vec<i64>> {
+    let data = input
+        .split_whitespace()
+        .map(|i| parse::number(input, i))
+        .collect::<Result<_, _>>()?;
+
+    Ok(data)
+}
+
+fn entry(data: &[i64], second_puzzle: bool) -> anyhow::Result<usize> {
+    let mut data: Vec<i128> = data.iter().map(|&d| d.into()).collect();
+
+    if second_puzzle {
+        data = data.windows(3).map(|w| w.iter().sum()).collect();
+    }
+
+    let result = data.windows(2).filter(|w| w[0] < w[1]).count();
+
+    Ok(result)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: Day = Day(1);
+
+    type Input = Vec<i64>;
+    type First = usize;
+    type Second = usize;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<usize> {
+        entry(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<usize> {
+        entry(input, true)
+    }
+}
@@ -0,0 +1,116 @@
+use anyhow::{anyhow, bail};
+
+use crate::{
+    parse::{self, ParseError},
+    Day, Solution,
+};
+
+pub enum Direction {
+    Up,
+    Down,
+    Forward,
+}
+
+impl Direction {
+    fn get_xy_dir(&self) -> (i64, i64) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Forward => (1, 0),
+        }
+    }
+}
+
+pub struct Instruction {
+    direction: Direction,
+    length: i64,
+}
+
+fn parse(text: &str) -> anyhow::Result<Vec<Instruction>> {
+    text.trim()
+        .split('\n')
+        .map(|i| {
+            let mut instruction = i.split_whitespace();
+
+            let direction = instruction
+                .next()
+                .ok_or_else(|| ParseError::new(text, i, "Missing direction"))?;
+            let direction = match direction {
+                "forward" => Direction::Forward,
+                "up" => Direction::Up,
+                "down" => Direction::Down,
+                _ => bail!(ParseError::new(
+                    text,
+                    direction,
+                    format!("Invalid direction '{}'", direction)
+                )),
+            };
+
+            let length = instruction
+                .next()
+                .ok_or_else(|| ParseError::new(text, i, "Missing distance"))?;
+            let length = parse::number::<u32>(text, length)?.into();
+
+            Ok(Instruction { direction, length })
+        })
+        .collect()
+}
+
+fn overflow() -> anyhow::Error {
+    anyhow!("The position does not fit into 64 bits.")
+}
+
+fn part1(instructions: &[Instruction]) -> anyhow::Result<i64> {
+    let mut x: i64 = 0;
+    let mut y: i64 = 0;
+
+    for i in instructions {
+        let (dx, dy) = i.direction.get_xy_dir();
+        x = x.checked_add(dx * i.length).ok_or_else(overflow)?;
+        y = y.checked_add(dy * i.length).ok_or_else(overflow)?;
+    }
+
+    x.checked_mul(y).ok_or_else(overflow)
+}
+
+fn part2(instructions: &[Instruction]) -> anyhow::Result<i64> {
+    let mut x: i64 = 0;
+    let mut y: i64 = 0;
+    let mut aim: i64 = 0;
+
+    for instr in instructions {
+        match instr.direction {
+            Direction::Up => aim -= instr.length,
+            Direction::Down => aim += instr.length,
+            Direction::Forward => {
+                x = x.checked_add(instr.length).ok_or_else(overflow)?;
+                let dy = aim.checked_mul(instr.length).ok_or_else(overflow)?;
+                y = y.checked_add(dy).ok_or_else(overflow)?;
+            }
+        }
+    }
+
+    x.checked_mul(y).ok_or_else(overflow)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: Day = Day(2);
+
+    type Input = Vec<Instruction>;
+    type First = i64;
+    type Second = i64;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<i64> {
+        part2(input)
+    }
+}
@@ -0,0 +1,179 @@
+use anyhow::bail;
+
+use crate::{parse::ParseError, Day, Solution};
+
+pub struct Report {
+    value_width: usize,
+    values: Vec<u32>,
+}
+
+fn bin_str_to_u32(text: &str, s: &str) -> anyhow::Result<u32> {
+    let mut result = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '0' => {
+                result <<= 1;
+            }
+            '1' => {
+                result += 1;
+                result <<= 1;
+            }
+            _ => bail!(ParseError::new(
+                text,
+                &s[i..i + c.len_utf8()],
+                format!("Invalid character '{}' in binary string", c)
+            )),
+        }
+    }
+
+    result >>= 1;
+
+    Ok(result)
+}
+
+fn parse(text: &str) -> anyhow::Result<Report> {
+    let first = text
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| ParseError::end(text, "No data in file"))?;
+    let value_width = first.chars().count();
+
+    if value_width >= 32 {
+        bail!(ParseError::new(
+            text,
+            first,
+            format!(
+                "Values with {} bits are too wide, at most 31 are supported",
+                value_width
+            )
+        ));
+    }
+
+    let values = text
+        .split_whitespace()
+        .map(|s| {
+            if s.len() != value_width {
+                bail!(ParseError::new(
+                    text,
+                    s,
+                    format!("Value '{}' does not have {} bits", s, value_width)
+                ));
+            }
+            bin_str_to_u32(text, s)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Report {
+        value_width,
+        values,
+    })
+}
+
+fn part1(report: &Report) -> anyhow::Result<u64> {
+    let value_width = report.value_width;
+
+    let mut num_total_values = 0;
+    let mut digits = vec![0; value_width];
+
+    for mut num in report.values.iter().copied() {
+        for i in (0..value_width).rev() {
+            digits[i] += if num & 1 == 1 { 1 } else { 0 };
+            num >>= 1;
+        }
+
+        num_total_values += 1;
+    }
+
+    let mut result = 0;
+
+    for i in digits {
+        result |= (2 * i > num_total_values) as u32;
+        result <<= 1;
+    }
+
+    result >>= 1;
+
+    let gamma_rate = result;
+    let epsilon_rate = !result & !(u32::MAX << value_width);
+
+    Ok(u64::from(gamma_rate) * u64::from(epsilon_rate))
+}
+
+fn filter_value_with_bit(data: &mut Vec<u32>, bit: usize, bit_is_one: bool) {
+    let cmp_fn = if bit_is_one { |x| x > 0 } else { |x| x == 0 };
+
+    data.retain(|&v| cmp_fn(v & (1 << bit)));
+}
+
+/// Keeps the values whose bit is the one chosen by `keep_ones` from the number of
+/// ones and zeros, starting at the highest bit, until a single value is left.
+fn find_rating(
+    mut data: Vec<u32>,
+    value_width: usize,
+    keep_ones: fn(usize, usize) -> bool,
+) -> anyhow::Result<u32> {
+    for current_bit in (0..value_width).rev() {
+        if data.len() <= 1 {
+            break;
+        }
+
+        let counted_ones = data
+            .iter()
+            .filter(|&&value| value & (1 << current_bit) > 0)
+            .count();
+        let counted_zeroes = data.len() - counted_ones;
+
+        // Values which all share the bit are kept, none of them is filtered out.
+        if counted_ones > 0 && counted_zeroes > 0 {
+            let bit_is_one = keep_ones(counted_ones, counted_zeroes);
+            filter_value_with_bit(&mut data, current_bit, bit_is_one);
+        }
+    }
+
+    match data[..] {
+        [value] => Ok(value),
+        [] => bail!("No solution found."),
+        _ => bail!("No unique solution found."),
+    }
+}
+
+fn find_oxygen_rating(data: Vec<u32>, value_width: usize) -> anyhow::Result<u32> {
+    find_rating(data, value_width, |ones, zeroes| ones >= zeroes)
+}
+
+fn find_scrubber_rating(data: Vec<u32>, value_width: usize) -> anyhow::Result<u32> {
+    find_rating(data, value_width, |ones, zeroes| ones < zeroes)
+}
+
+fn part2(report: &Report) -> anyhow::Result<u64> {
+    let value_width = report.value_width;
+    let data = &report.values;
+
+    let oxygen_rating = find_oxygen_rating(data.clone(), value_width)?;
+    let scrubber_rating = find_scrubber_rating(data.clone(), value_width)?;
+
+    Ok(u64::from(oxygen_rating) * u64::from(scrubber_rating))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: Day = Day(3);
+
+    type Input = Report;
+    type First = u64;
+    type Second = u64;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u64> {
+        part2(input)
+    }
+}
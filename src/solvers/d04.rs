@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail};
+
+use crate::{
+    parse::{self, ParseError},
+    Day, Solution,
+};
+
+const BOARD_SIZE: usize = 5;
+
+#[derive(Clone)]
+struct Board<T> {
+    tiles: Vec<T>,
+    size: u32,
+}
+
+impl<T: Clone> Board<T> {
+    fn new(size: u32, value: T) -> Self {
+        let tiles = vec![value; size as usize * size as usize];
+
+        Self { tiles, size }
+    }
+
+    fn get(&self, x: i32, y: i32) -> Option<&T> {
+        if 0 > x || x >= self.size as i32 {
+            return None;
+        }
+        if 0 > y || y >= self.size as i32 {
+            return None;
+        }
+
+        let x = x as usize;
+        let y = y as usize;
+        let size = self.size as usize;
+
+        Some(&self.tiles[x + y * size])
+    }
+
+    fn set(&mut self, x: i32, y: i32, value: T) {
+        assert!(0 <= x);
+        assert!(0 <= y);
+        assert!(x < self.size as i32);
+        assert!(y < self.size as i32);
+
+        let x = x as usize;
+        let y = y as usize;
+        let size = self.size as usize;
+
+        self.tiles[x + y * size] = value;
+    }
+}
+
+impl Board<bool> {
+    fn bingo_at(&self, x: u32, y: u32) -> bool {
+        let x = x as i32;
+        let y = y as i32;
+
+        let dx1 = (x..self.size as _)
+            .take_while(|&x| *self.get(x, y).unwrap_or(&false))
+            .count();
+        let dx2 = (0..x)
+            .rev()
+            .take_while(|&x| *self.get(x, y).unwrap_or(&false))
+            .count();
+        let vertical = dx1 + dx2;
+
+        let dy1 = (y..self.size as _)
+            .take_while(|&y| *self.get(x, y).unwrap_or(&false))
+            .count();
+        let dy2 = (0..y)
+            .rev()
+            .take_while(|&y| *self.get(x, y).unwrap_or(&false))
+            .count();
+        let horizontal = dy1 + dy2;
+
+        vertical >= 5 || horizontal >= 5
+    }
+}
+
+#[derive(Debug)]
+pub struct BoardMap {
+    /// Position of every number on the board.
+    map: HashMap<u32, (u32, u32)>,
+}
+
+#[derive(Debug)]
+pub struct ParseResult {
+    sequence: Vec<u32>,
+    boards: Vec<BoardMap>,
+}
+
+fn parse_number_sequence(
+    text: &str,
+    sequence: &str,
+) -> Result<Vec<u32>, ParseError> {
+    sequence
+        .trim()
+        .split(',')
+        .map(|n| parse::number(text, n.trim()))
+        .collect()
+}
+
+fn parse_bingo_board(text: &str, board: &str) -> anyhow::Result<BoardMap> {
+    let lines: Vec<_> = board.trim().lines().collect();
+
+    if lines.len() != BOARD_SIZE {
+        bail!(ParseError::new(
+            text,
+            board.trim(),
+            format!("Board has {} rows instead of {}", lines.len(), BOARD_SIZE)
+        ));
+    }
+
+    let mut result = HashMap::new();
+
+    for (y, line) in lines.into_iter().enumerate() {
+        let numbers: Vec<_> = line.split_whitespace().collect();
+
+        if numbers.len() != BOARD_SIZE {
+            bail!(ParseError::new(
+                text,
+                line,
+                format!(
+                    "Board row has {} numbers instead of {}",
+                    numbers.len(),
+                    BOARD_SIZE
+                )
+            ));
+        }
+
+        for (x, n) in numbers.into_iter().enumerate() {
+            let num: u32 = parse::number(text, n)?;
+
+            if result.insert(num, (x as _, y as _)).is_some() {
+                bail!(ParseError::new(
+                    text,
+                    n,
+                    format!("Number {} is already on the board", num)
+                ));
+            }
+        }
+    }
+
+    Ok(BoardMap { map: result })
+}
+
+fn parse(text: &str) -> anyhow::Result<ParseResult> {
+    let mut elements = text.trim().split("\n\n");
+
+    let sequence = elements.next().ok_or_else(|| {
+        ParseError::end(text, "Missing number sequence at beginning")
+    })?;
+
+    let sequence = parse_number_sequence(text, sequence)?;
+
+    let boards = elements
+        .map(|e| parse_bingo_board(text, e))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ParseResult { sequence, boards })
+}
+
+fn sum_unmarked(placed: &Board<bool>, map: &BoardMap) -> u64 {
+    let sum_unmarked: u64 = map
+        .map
+        .iter()
+        .filter(|(_, &(x, y))| !placed.get(x as _, y as _).unwrap_or(&false))
+        .map(|(&num, _)| u64::from(num))
+        .sum();
+
+    sum_unmarked
+}
+
+fn score(sum_unmarked: u64, num: u32) -> anyhow::Result<u64> {
+    sum_unmarked
+        .checked_mul(num.into())
+        .ok_or(anyhow!("The score does not fit into 64 bits."))
+}
+
+fn part1(input: &ParseResult) -> anyhow::Result<u64> {
+    let ParseResult { sequence, boards } = input;
+
+    let placed = vec![Board::new(BOARD_SIZE as u32, false); boards.len()];
+
+    let mut boards: Vec<_> = placed.into_iter().zip(boards).collect();
+
+    for &num in sequence {
+        for (board, map) in boards.iter_mut() {
+            let (x, y) = match map.map.get(&num) {
+                Some(&position) => position,
+                None => continue,
+            };
+
+            board.set(x as _, y as _, true);
+
+            if board.bingo_at(x, y) {
+                let sum_unmarked = sum_unmarked(&*board, map);
+
+                return score(sum_unmarked, num);
+            }
+        }
+    }
+
+    Err(anyhow!("No bingo occured."))
+}
+
+fn part2(input: &ParseResult) -> anyhow::Result<u64> {
+    let ParseResult { sequence, boards } = input;
+
+    let placed = vec![Board::new(BOARD_SIZE as u32, false); boards.len()];
+
+    let mut boards: Vec<_> = placed.into_iter().zip(boards).collect();
+
+    for &num in sequence {
+        // Removes the boards with a bingo, remembering the last one.
+        let mut last_board = None;
+        let mut i = 0;
+        while i < boards.len() {
+            let (placed, map) = &mut boards[i];
+            let bingo = match map.map.get(&num) {
+                Some(&(x, y)) => {
+                    placed.set(x as _, y as _, true);
+                    placed.bingo_at(x, y)
+                }
+                None => false,
+            };
+
+            if bingo {
+                last_board = Some(boards.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+
+        if boards.is_empty() {
+            let result_board = last_board.ok_or(anyhow!("There are no boards."))?;
+            let sum_unmarked = sum_unmarked(&result_board.0, result_board.1);
+            return score(sum_unmarked, num);
+        }
+    }
+
+    Err(anyhow!("No bingo occured."))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: Day = Day(4);
+
+    type Input = ParseResult;
+    type First = u64;
+    type Second = u64;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u64> {
+        part2(input)
+    }
+}
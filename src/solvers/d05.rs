@@ -0,0 +1,245 @@
+use anyhow::{anyhow, bail};
+use owo_colors::AnsiColors;
+
+use crate::{
+    geom::{BoundingBox, Point2},
+    parse::{self, ParseError},
+    visualize::{Canvas, Cell, Frame},
+    Day, Puzzle, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    start: Point2<i32>,
+    end: Point2<i32>,
+}
+
+impl Line {
+    fn is_horizontal(&self) -> bool {
+        self.start.y() == self.end.y()
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.start.x() == self.end.x()
+    }
+
+    fn iter_points(&self) -> impl Iterator<Item = Point2<i32>> + '_ {
+        let len = self.start.chebyshev(self.end);
+
+        // Lines are horizontal, vertical or diagonal, which is checked by `parse`.
+        let step = (self.end - self.start).map(i32::signum);
+
+        (0..=len).map(move |t| self.start + step * t)
+    }
+}
+fn parse_point(text: &str, point: &str) -> anyhow::Result<Point2<i32>> {
+    let point = point.trim();
+    let mut coords = point.split(',');
+
+    let mut coord = |name| -> anyhow::Result<i32> {
+        let part = coords
+            .next()
+            .ok_or_else(|| {
+                ParseError::new(text, point, format!("Missing {} coordinate", name))
+            })?
+            .trim();
+        let c = parse::number(text, part)?;
+
+        if c < 0 {
+            bail!(ParseError::new(
+                text,
+                part,
+                format!("Negative {} coordinate", name)
+            ));
+        }
+
+        Ok(c)
+    };
+
+    let x = coord("x")?;
+    let y = coord("y")?;
+
+    Ok(Point2::new(x, y))
+}
+
+fn parse_line(text: &str, line: &str) -> anyhow::Result<Line> {
+    let mut points = line.trim().split("->");
+
+    let start = points
+        .next()
+        .ok_or_else(|| ParseError::new(text, line, "Missing start point"))?;
+    let start = parse_point(text, start)?;
+    let end = points
+        .next()
+        .ok_or_else(|| ParseError::new(text, line, "Missing end point"))?;
+    let end = parse_point(text, end)?;
+
+    let delta = end - start;
+    let (dx, dy) = (delta.x().abs(), delta.y().abs());
+
+    if dx != 0 && dy != 0 && dx != dy {
+        bail!(ParseError::new(
+            text,
+            line.trim(),
+            "Line is neither horizontal, vertical nor diagonal"
+        ));
+    }
+
+    Ok(Line { start, end })
+}
+
+fn parse(input: &str) -> anyhow::Result<Vec<Line>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| parse_line(input, line))
+        .collect::<Result<Vec<_>, _>>()
+}
+
+/// Most points of a field, which is dense, so huge coordinates are an error
+/// instead of an allocation failure.
+const MAX_FIELD_SIZE: usize = 1 << 26;
+
+struct Field {
+    width: usize,
+    height: usize,
+
+    data: Vec<u8>,
+}
+
+impl Field {
+    fn new(width: usize, height: usize) -> anyhow::Result<Self> {
+        let size = width
+            .checked_mul(height)
+            .filter(|&size| size <= MAX_FIELD_SIZE)
+            .ok_or_else(|| {
+                anyhow!(
+                    "The field of {}x{} points is too large, at most {} points are supported.",
+                    width,
+                    height,
+                    MAX_FIELD_SIZE
+                )
+            })?;
+
+        Ok(Self {
+            width,
+            height,
+            data: vec![0; size],
+        })
+    }
+
+    fn get_mut(&mut self, p: Point2<i32>) -> Option<&mut u8> {
+        let x = usize::try_from(p.x()).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(p.y()).ok().filter(|&y| y < self.height)?;
+
+        self.data.get_mut(x + y * self.width)
+    }
+
+    fn place_line(&mut self, line: &Line) -> anyhow::Result<()> {
+        for p in line.iter_points() {
+            let v = self.get_mut(p).ok_or_else(|| {
+                anyhow!("The point {},{} is outside of the field.", p.x(), p.y())
+            })?;
+            *v = v.saturating_add(1);
+        }
+
+        Ok(())
+    }
+
+    fn iter_points(&self) -> impl Iterator<Item = u8> + '_ {
+        self.data.iter().copied()
+    }
+
+    /// Number of lines at every point, overlaps in bold red.
+    fn to_frame(&self) -> Frame {
+        let rows = self
+            .data
+            .chunks(self.width)
+            .map(|row| {
+                row.iter()
+                    .map(|&count| match count {
+                        0 => Cell::plain('.'),
+                        1 => Cell::plain('1'),
+                        _ => {
+                            let symbol =
+                                char::from_digit(count as u32, 10).unwrap_or('+');
+                            Cell::colored(symbol, AnsiColors::Red).bold()
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Frame::Grid(rows)
+    }
+}
+
+/// Lines which are considered for `puzzle`.
+fn line_filter(puzzle: Puzzle) -> fn(&Line) -> bool {
+    match puzzle {
+        Puzzle::First => |line| line.is_horizontal() || line.is_vertical(),
+        Puzzle::Second => |_| true,
+    }
+}
+
+fn place_lines(
+    lines: &[Line],
+    line_filter: impl Fn(&Line) -> bool,
+) -> anyhow::Result<Field> {
+    // The field covers every line, even if none of them pass the filter.
+    let bounds =
+        BoundingBox::from_points(lines.iter().flat_map(|l| [l.start, l.end]))
+            .ok_or(anyhow!("No lines in input"))?;
+
+    // Coordinates are not negative, which is checked by `parse`.
+    let mut field =
+        Field::new(bounds.max.x() as usize + 1, bounds.max.y() as usize + 1)?;
+
+    for line in lines.iter().filter(|l| line_filter(l)) {
+        field.place_line(line)?;
+    }
+
+    Ok(field)
+}
+
+fn solve(
+    lines: &[Line],
+    line_filter: impl Fn(&Line) -> bool,
+) -> anyhow::Result<usize> {
+    let field = place_lines(lines, line_filter)?;
+
+    let result = field.iter_points().filter(|&i| i >= 2).count();
+    Ok(result)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: Day = Day(5);
+
+    type Input = Vec<Line>;
+    type First = usize;
+    type Second = usize;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<usize> {
+        solve(input, line_filter(Puzzle::First))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<usize> {
+        solve(input, line_filter(Puzzle::Second))
+    }
+
+    fn visualize(
+        input: &Self::Input,
+        puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        let field = place_lines(input, line_filter(puzzle))?;
+
+        canvas.draw(field.to_frame())
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::VecDeque;
+
+use anyhow::bail;
+use num::{BigUint, Zero};
+
+use crate::{
+    parse::{self, ParseError},
+    Day, Param, Params, Puzzle, Solution,
+};
+
+/// Number of days until a new lantern fish creates its first offspring.
+const MAX_TIMER: usize = 8;
+
+fn parse(input: &str) -> anyhow::Result<Vec<usize>> {
+    input
+        .trim()
+        .split(',')
+        .map(|l| {
+            let timer = parse::number(input, l.trim())?;
+
+            if timer > MAX_TIMER {
+                bail!(ParseError::new(
+                    input,
+                    l.trim(),
+                    format!("Timer {} is larger than {}", timer, MAX_TIMER)
+                ));
+            }
+
+            Ok(timer)
+        })
+        .collect()
+}
+
+/// The lanternfish grow exponentially, so they are counted without a bound.
+fn simulate(input: &[usize], days: usize) -> BigUint {
+    let mut data = VecDeque::<BigUint>::new();
+    data.resize(MAX_TIMER + 1, BigUint::zero());
+
+    for &i in input {
+        data[i] += 1u32;
+    }
+
+    for _day in 0..days {
+        data.rotate_left(1);
+        let f = data[MAX_TIMER].clone();
+        data[6] += f;
+    }
+
+    data.into_iter().sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: Day = Day(6);
+
+    type Input = Vec<usize>;
+    type First = BigUint;
+    type Second = BigUint;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "days",
+        description: "Days the lanternfish multiply",
+        first: Some("80"),
+        second: Some("256"),
+    }];
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<BigUint> {
+        Self::part1_with(input, &Params::defaults(Self::PARAMS, Puzzle::First))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<BigUint> {
+        Self::part2_with(input, &Params::defaults(Self::PARAMS, Puzzle::Second))
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> anyhow::Result<BigUint> {
+        Ok(simulate(input, params.get("days")?))
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> anyhow::Result<BigUint> {
+        Ok(simulate(input, params.get("days")?))
+    }
+}
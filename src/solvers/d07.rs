@@ -0,0 +1,75 @@
+use anyhow::anyhow;
+
+use crate::{parse, Day, Solution};
+
+fn parse(input: &str) -> anyhow::Result<Vec<i32>> {
+    let positions = input
+        .trim()
+        .split(',')
+        .map(|l| parse::number(input, l.trim()))
+        .collect::<Result<_, _>>()?;
+
+    Ok(positions)
+}
+
+fn part1(input: &[i32]) -> anyhow::Result<i64> {
+    let mut input: Vec<i64> = input.iter().map(|&i| i.into()).collect();
+
+    input.sort();
+
+    let pos = *input.get(input.len() / 2).ok_or(anyhow!("Input empty."))?;
+
+    let result = input.into_iter().map(|i| (i - pos).abs()).sum();
+    Ok(result)
+}
+
+fn cost(start: i32, end: i32) -> i128 {
+    let dist = (i128::from(end) - i128::from(start)).abs();
+    dist * (dist + 1) / 2
+}
+
+fn eval_position(pos: i32, crabs: &[i32]) -> i128 {
+    crabs
+        .iter()
+        .copied()
+        .map(|crab_pos| cost(crab_pos, pos))
+        .sum()
+}
+
+fn part2(input: &[i32]) -> anyhow::Result<i128> {
+    let mut input = input.to_vec();
+
+    input.sort();
+
+    let max = input.last().copied().ok_or(anyhow!("Input empty."))?;
+    let min = input.first().copied().ok_or(anyhow!("Input empty."))?;
+
+    let result = (min..=max)
+        .map(|i| eval_position(i, &input))
+        .min()
+        .ok_or(anyhow!("Input empty."))?;
+
+    Ok(result)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: Day = Day(7);
+
+    type Input = Vec<i32>;
+    type First = i64;
+    type Second = i128;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<i128> {
+        part2(input)
+    }
+}
@@ -2,8 +2,10 @@ use std::collections::BTreeSet;
 
 use anyhow::{anyhow, bail};
 
+use crate::{parse::ParseError, Day, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-enum Segment {
+pub enum Segment {
     A,
     B,
     C,
@@ -16,16 +18,15 @@ enum Segment {
 type Digit = BTreeSet<Segment>;
 
 #[derive(Debug)]
-struct InputLine {
+pub struct InputLine {
     digits: Vec<Digit>,
     sequence: Vec<Digit>,
 }
 
-fn parse_digit(digit: &str) -> anyhow::Result<Digit> {
+fn parse_digit(text: &str, digit: &str) -> anyhow::Result<Digit> {
     let set = digit
-        .trim()
-        .chars()
-        .map(|c| {
+        .char_indices()
+        .map(|(i, c)| {
             Ok(match c {
                 'a' => Segment::A,
                 'b' => Segment::B,
@@ -34,7 +35,11 @@ fn parse_digit(digit: &str) -> anyhow::Result<Digit> {
                 'e' => Segment::E,
                 'f' => Segment::F,
                 'g' => Segment::G,
-                _ => bail!("Invalid character '{}'", c),
+                _ => bail!(ParseError::new(
+                    text,
+                    &digit[i..i + c.len_utf8()],
+                    format!("Invalid character '{}'", c)
+                )),
             })
         })
         .collect::<Result<_, _>>()?;
@@ -47,19 +52,21 @@ fn parse(input: &str) -> anyhow::Result<Vec<InputLine>> {
         .lines()
         .map(|line| {
             let mut parts = line.split('|');
-            let digits = parts.next().ok_or(anyhow!("Missing digits"))?;
+            let digits = parts
+                .next()
+                .ok_or_else(|| ParseError::new(input, line, "Missing digits"))?;
 
             let digits = digits
-                .trim()
                 .split_whitespace()
-                .map(parse_digit)
+                .map(|d| parse_digit(input, d))
                 .collect::<Result<_, _>>()?;
 
-            let sequence = parts.next().ok_or(anyhow!("Missing sequence"))?;
+            let sequence = parts
+                .next()
+                .ok_or_else(|| ParseError::new(input, line, "Missing sequence"))?;
             let sequence = sequence
-                .trim()
                 .split_whitespace()
-                .map(parse_digit)
+                .map(|d| parse_digit(input, d))
                 .collect::<Result<_, _>>()?;
 
             Ok(InputLine { digits, sequence })
@@ -67,39 +74,47 @@ fn parse(input: &str) -> anyhow::Result<Vec<InputLine>> {
         .collect()
 }
 
+/// The only item, errors if there is none or several.
+fn single<T>(items: impl IntoIterator<Item = T>, what: &str) -> anyhow::Result<T> {
+    let mut items = items.into_iter();
+
+    match (items.next(), items.next()) {
+        (Some(item), None) => Ok(item),
+        (None, _) => bail!("Can not identify {}, there is no candidate.", what),
+        (Some(_), Some(_)) => bail!("Can not identify {}, it is ambiguous.", what),
+    }
+}
+
 fn decode_line(
-    InputLine { digits, sequence }: InputLine,
+    InputLine { digits, sequence }: &InputLine,
 ) -> anyhow::Result<Vec<usize>> {
     // These four digits are uniquely identifiable
     let one_digit = digits
         .iter()
-        .filter(|b| b.len() == 2)
-        .next()
+        .find(|b| b.len() == 2)
         .ok_or(anyhow!("No two segment digit."))?
         .clone();
 
     let four_digit = digits
         .iter()
-        .filter(|b| b.len() == 4)
-        .next()
+        .find(|b| b.len() == 4)
         .ok_or(anyhow!("No four segment digit."))?;
 
     let seven_digit = digits
         .iter()
-        .filter(|b| b.len() == 3)
-        .next()
+        .find(|b| b.len() == 3)
         .ok_or(anyhow!("No three segment digit."))?
         .clone();
 
     let eight_digit = digits
         .iter()
-        .filter(|b| b.len() == 7)
-        .next()
+        .find(|b| b.len() == 7)
         .ok_or(anyhow!("No seven segment digit."))?
         .clone();
 
     // A segment is the difference in segments of the digits seven and one.
-    let a_segment = seven_digit.difference(&one_digit).copied().next().unwrap();
+    let a_segment =
+        single(seven_digit.difference(&one_digit).copied(), "segment a")?;
 
     // By subtracting one from digit four, we are left with B and D segments, which, allows us
     // To identify the zero digit because it is the only digit with 6 segments that only looses
@@ -109,29 +124,27 @@ fn decode_line(
 
     let s: Digit = eight_digit.difference(&b_and_d_segments).copied().collect();
 
-    let zero_digit: Vec<_> =
-        digits.iter().filter(|b| b.len() == 6).cloned().collect();
-    let mut zero_digit: Vec<_> = zero_digit
-        .into_iter()
-        .filter(|b| b.difference(&s).count() == 1)
-        .collect();
-    assert_eq!(zero_digit.len(), 1);
-    let zero_digit: Digit = zero_digit.pop().unwrap();
+    let zero_digit: Digit = single(
+        digits
+            .iter()
+            .filter(|b| b.len() == 6)
+            .filter(|b| b.difference(&s).count() == 1)
+            .cloned(),
+        "digit zero",
+    )?;
 
     // With the zero digit we can get D by subtracting it from digit 8, which then can be removed
     // from b_and_d_segments to get d.
-    let mut d_segment: Vec<_> =
-        eight_digit.difference(&zero_digit).copied().collect();
-    assert_eq!(d_segment.len(), 1);
-    let d_segment = d_segment.pop().unwrap();
+    let d_segment =
+        single(eight_digit.difference(&zero_digit).copied(), "segment d")?;
 
-    let mut b_segment: Vec<_> = b_and_d_segments
-        .iter()
-        .filter(|&&s| s != d_segment)
-        .copied()
-        .collect();
-    assert_eq!(b_segment.len(), 1);
-    let b_segment = b_segment.pop().unwrap();
+    let b_segment = single(
+        b_and_d_segments
+            .iter()
+            .filter(|&&s| s != d_segment)
+            .copied(),
+        "segment b",
+    )?;
 
     // We can identify the five digit be cause it is the only five segment digit
     // Which has two segments left, when we subtract a, b and d, which leaves us
@@ -139,39 +152,36 @@ fn decode_line(
     let a_b_d_segments: Digit =
         [a_segment, b_segment, d_segment].into_iter().collect();
 
-    let f_and_g_segments: Vec<_> = digits
-        .iter()
-        .filter_map(|b| {
+    let f_or_g_segments: Digit = single(
+        digits.iter().filter_map(|b| {
             if b.len() != 5 {
                 return None;
             }
             let diff: Digit = b.difference(&a_b_d_segments).copied().collect();
-            (diff.len() == 2).then_some(diff)
-        })
-        .collect();
-    assert_eq!(f_and_g_segments.len(), 1);
-    let f_or_g_segments: Digit = f_and_g_segments[0].clone();
-    assert_eq!(f_or_g_segments.len(), 2);
+            if diff.len() == 2 {
+                Some(diff)
+            } else {
+                None
+            }
+        }),
+        "segments f and g",
+    )?;
 
     // The intersection of {f, c} and {f, g} is f, which then allows us to extract c and g.
-    let mut f_segment: Vec<_> =
-        one_digit.intersection(&f_or_g_segments).copied().collect();
-    assert_eq!(f_segment.len(), 1);
-    let f_segment = f_segment.pop().unwrap();
+    let f_segment = single(
+        one_digit.intersection(&f_or_g_segments).copied(),
+        "segment f",
+    )?;
 
-    let g_segment = f_or_g_segments
-        .iter()
-        .filter(|&&b| b != f_segment)
-        .copied()
-        .next()
-        .unwrap();
+    let g_segment = single(
+        f_or_g_segments.iter().filter(|&&b| b != f_segment).copied(),
+        "segment g",
+    )?;
 
-    let c_segment = one_digit
-        .iter()
-        .filter(|&&b| b != f_segment)
-        .copied()
-        .next()
-        .unwrap();
+    let c_segment = single(
+        one_digit.iter().filter(|&&b| b != f_segment).copied(),
+        "segment c",
+    )?;
 
     // The only digit left is e, which we get by subtracting the segments of nine from digit eight.
     let nine_digit: Digit = vec![
@@ -180,10 +190,8 @@ fn decode_line(
     .into_iter()
     .collect();
 
-    let mut e_segment: Vec<_> =
-        eight_digit.difference(&nine_digit).copied().collect();
-    assert_eq!(e_segment.len(), 1);
-    let e_segment = e_segment.pop().unwrap();
+    let e_segment =
+        single(eight_digit.difference(&nine_digit).copied(), "segment e")?;
 
     // We have identified all digits, so now we're constructing all remaining digits.
     let zero_digit: Digit = vec![
@@ -232,16 +240,17 @@ fn decode_line(
 
     // With all digits we can now decode every digit and return.
 
-    let decode_digit =
-        |d: &Digit| digits.iter().enumerate().find(|(_, o)| &d == o).unwrap().0;
+    let decode_digit = |d: &Digit| {
+        digits
+            .iter()
+            .position(|o| d == o)
+            .ok_or(anyhow!("Segments {:?} are not a digit.", d))
+    };
 
-    let solution = sequence.iter().map(decode_digit).collect();
-    Ok(solution)
+    sequence.iter().map(decode_digit).collect()
 }
 
-pub fn part1(text: &str) -> anyhow::Result<usize> {
-    let input = parse(text)?;
-
+fn part1(input: &[InputLine]) -> anyhow::Result<usize> {
     let mut result = 0;
 
     for line in input {
@@ -253,22 +262,46 @@ pub fn part1(text: &str) -> anyhow::Result<usize> {
     Ok(result)
 }
 
-pub fn part2(text: &str) -> anyhow::Result<usize> {
-    let input = parse(text)?;
-
-    let mut result = 0;
+fn part2(input: &[InputLine]) -> anyhow::Result<usize> {
+    let mut result: usize = 0;
 
     for line in input {
         let digits = decode_line(line)?;
 
-        let mut s = 0;
+        let mut s: usize = 0;
         for d in digits {
-            s *= 10;
-            s += d;
+            s = s
+                .checked_mul(10)
+                .and_then(|s| s.checked_add(d))
+                .ok_or(anyhow!("The displayed number is too large."))?;
         }
 
-        result += s;
+        result = result
+            .checked_add(s)
+            .ok_or(anyhow!("The sum of the displayed numbers is too large."))?;
     }
 
     Ok(result)
 }
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: Day = Day(8);
+
+    type Input = Vec<InputLine>;
+    type First = usize;
+    type Second = usize;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<usize> {
+        part2(input)
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::anyhow;
+use owo_colors::AnsiColors;
+
+use crate::{
+    geom::Point2,
+    grid::Grid,
+    visualize::{Canvas, Cell, Frame},
+    Day, Puzzle, Solution,
+};
+
+fn parse(input: &str) -> anyhow::Result<Grid<u32>> {
+    let grid = Grid::parse(input, input, |digit| digit.to_digit(10))?;
+
+    Ok(grid)
+}
+
+fn is_low_point(grid: &Grid<u32>, p: Point2<i32>) -> bool {
+    let height = grid[p];
+
+    p.neighbors()
+        .all(|n| height < *grid.get_point(n).unwrap_or(&u32::MAX))
+}
+
+fn get_low_points(
+    grid: &Grid<u32>,
+) -> impl Iterator<Item = (Point2<i32>, u32)> + '_ {
+    grid.iter_coords::<i32>()
+        .map(|(x, y, v)| (Point2::new(x, y), *v))
+        .filter(move |&(p, _)| is_low_point(grid, p))
+}
+
+fn part1(grid: &Grid<u32>) -> anyhow::Result<u32> {
+    let result = get_low_points(grid).map(|(_, i)| i + 1).sum();
+
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct BasinID(usize);
+
+/// Heights with the basins in green and their low points in bold.
+fn basin_frame(grid: &Grid<u32>, basins: &Grid<Option<BasinID>>) -> Frame {
+    Frame::from_grid(grid, |x, y, &v| {
+        let symbol = char::from_digit(v, 10).unwrap_or('?');
+        let cell = if basins[(x, y)].is_some() {
+            Cell::colored(symbol, AnsiColors::Green)
+        } else {
+            Cell::plain(symbol)
+        };
+
+        if is_low_point(grid, Point2::new(x, y)) {
+            cell.bold()
+        } else {
+            cell
+        }
+    })
+}
+
+fn find_basins(grid: &Grid<u32>) -> Grid<Option<BasinID>> {
+    let mut basins = Grid::new(grid.get_width(), grid.get_height(), None);
+
+    let mut queue = VecDeque::new();
+
+    for (id, (p, _)) in get_low_points(grid).enumerate() {
+        basins[p] = Some(BasinID(id));
+        queue.push_back(p);
+    }
+
+    while let Some(p) = queue.pop_front() {
+        let basin_id = basins[p];
+
+        for p in p.neighbors().filter(|&n| grid.get_point(n).is_some()) {
+            if grid[p] < 9 && basins[p].is_none() {
+                basins[p] = basin_id;
+                queue.push_back(p);
+            }
+        }
+    }
+
+    basins
+}
+
+fn part2(grid: &Grid<u32>) -> anyhow::Result<u64> {
+    let basins = find_basins(grid);
+
+    let mut basin_sizes = HashMap::new();
+
+    for id in basins.iter().flat_map(|&i| i) {
+        *basin_sizes.entry(id).or_insert(0u64) += 1;
+    }
+
+    let mut basin_sizes: Vec<_> = basin_sizes.values().copied().collect();
+
+    basin_sizes.sort_by_key(|i| std::cmp::Reverse(*i));
+
+    if basin_sizes.len() < 3 {
+        return Err(anyhow!("Not enough basins for solution"));
+    }
+
+    Ok(basin_sizes[..3].iter().product())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: Day = Day(9);
+
+    type Input = Grid<u32>;
+    type First = u32;
+    type Second = u64;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u64> {
+        part2(input)
+    }
+
+    fn visualize(
+        input: &Self::Input,
+        puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        let basins = match puzzle {
+            Puzzle::First => Grid::new(input.get_width(), input.get_height(), None),
+            Puzzle::Second => find_basins(input),
+        };
+
+        canvas.draw(basin_frame(input, &basins))
+    }
+}
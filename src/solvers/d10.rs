@@ -1,7 +1,9 @@
-use anyhow::bail;
+use anyhow::{anyhow, bail};
+
+use crate::{parse::ParseError, Day, Solution};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum BracketType {
+pub enum BracketType {
     Normal,
     Square,
     Curly,
@@ -9,18 +11,18 @@ enum BracketType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
+pub enum Direction {
     Open,
     Close,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Bracket {
+pub struct Bracket {
     ty: BracketType,
     dir: Direction,
 }
 
-fn parse_bracket(c: char) -> anyhow::Result<Bracket> {
+fn parse_bracket(text: &str, part: &str, c: char) -> anyhow::Result<Bracket> {
     use BracketType::*;
     use Direction::*;
     #[rustfmt::skip]
@@ -33,7 +35,7 @@ fn parse_bracket(c: char) -> anyhow::Result<Bracket> {
         '}' => Bracket { ty: Curly,  dir: Close },
         '<' => Bracket { ty: Angled, dir: Open },
         '>' => Bracket { ty: Angled, dir: Close },
-        _ => bail!("'{}' is not a bracket", c)
+        _ => bail!(ParseError::new(text, part, format!("'{}' is not a bracket", c)))
     };
     Ok(b)
 }
@@ -43,9 +45,9 @@ fn parse(input: &str) -> anyhow::Result<Vec<Vec<Bracket>>> {
         .trim()
         .lines()
         .map(|line| {
-            line.trim()
-                .chars()
-                .map(parse_bracket)
+            let line = line.trim();
+            line.char_indices()
+                .map(|(i, c)| parse_bracket(input, &line[i..i + c.len_utf8()], c))
                 .collect::<Result<Vec<_>, _>>()
         })
         .collect()
@@ -92,13 +94,11 @@ fn verify_line(input: &[Bracket]) -> VerifyResult {
     VerifyResult::Ok
 }
 
-pub fn part1(text: &str) -> anyhow::Result<u64> {
-    let input = parse(text)?;
-
+fn part1(input: &[Vec<Bracket>]) -> anyhow::Result<u64> {
     let mut score = 0;
 
     for line in input {
-        match verify_line(&line) {
+        match verify_line(line) {
             VerifyResult::Illegal(pos) => {
                 score += match line[pos].ty {
                     BracketType::Normal => 3,
@@ -114,12 +114,10 @@ pub fn part1(text: &str) -> anyhow::Result<u64> {
     Ok(score)
 }
 
-pub fn part2(text: &str) -> anyhow::Result<u64> {
-    let input = parse(text)?;
-
-    let mut scores: Vec<_> = input
+fn part2(input: &[Vec<Bracket>]) -> anyhow::Result<u64> {
+    let mut scores: Vec<u64> = input
         .iter()
-        .map(|line| verify_line(&line))
+        .map(|line| verify_line(line))
         .filter_map(|v| {
             if let VerifyResult::Incomplete { expected_tokens } = v {
                 Some(expected_tokens)
@@ -137,13 +135,38 @@ pub fn part2(text: &str) -> anyhow::Result<u64> {
                     BracketType::Curly => 3,
                     BracketType::Angled => 4,
                 })
-                .fold(0, |acc, n| acc * 5 + n)
+                .try_fold(0u64, |acc, n| acc.checked_mul(5)?.checked_add(n))
+                .ok_or(anyhow!("The completion score does not fit into 64 bits."))
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
     scores.sort();
 
-    let score = scores[scores.len() / 2];
+    let score = *scores
+        .get(scores.len() / 2)
+        .ok_or(anyhow!("There are no incomplete lines."))?;
 
     Ok(score)
 }
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: Day = Day(10);
+
+    type Input = Vec<Vec<Bracket>>;
+    type First = u64;
+    type Second = u64;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u64> {
+        part2(input)
+    }
+}
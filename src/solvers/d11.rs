@@ -0,0 +1,207 @@
+use std::collections::{BTreeSet, HashSet};
+
+use anyhow::bail;
+use owo_colors::AnsiColors;
+
+use crate::{
+    geom::Point2,
+    grid::Grid,
+    simulation::{self, Simulation},
+    visualize::{Canvas, Cell, Frame},
+    Day, Param, Params, Puzzle, Solution,
+};
+
+fn parse(input: &str) -> anyhow::Result<Grid<u32>> {
+    let grid = Grid::parse(input, input, |digit| digit.to_digit(10))?;
+
+    Ok(grid)
+}
+
+/// Simulates a step of octopi flashing.
+/// Returns the positions of all discharged octopi.
+fn simulate_step(
+    grid: &mut Grid<u32>,
+    worklist: &mut BTreeSet<Point2<i32>>,
+) -> HashSet<Point2<i32>> {
+    assert!(worklist.is_empty());
+
+    // Increase every octopus level and remember in worklist which will flash.
+    for (x, y, v) in grid.iter_coords_mut() {
+        *v += 1;
+
+        if *v > 9 {
+            worklist.insert(Point2::new(x, y));
+        }
+    }
+
+    let mut flashes = HashSet::new();
+
+    // For every flash, remember who flashed and increase level of surrounding octopi.
+    // If a surrounding octopus has reached a sufficiently high level to flash, append it to
+    // the worklist.
+    while let Some(p) = worklist.pop_first() {
+        assert!(grid[p] > 9);
+
+        grid[p] -= 10;
+        assert!(flashes.insert(p), "duplicate flash at {:?}", p);
+
+        for n in p.adjacent() {
+            if let Some(v) = grid.get_point_mut(n) {
+                *v += 1;
+
+                if *v > 9 {
+                    worklist.insert(n);
+                }
+            }
+        }
+    }
+
+    // After a step every flashed octopus has to be at level zero.
+    for &p in &flashes {
+        grid[p] = 0;
+    }
+
+    flashes
+}
+
+/// Energy levels with the octopuses which flashed in bold.
+fn grid_frame(grid: &Grid<u32>, flashes: &HashSet<Point2<i32>>) -> Frame {
+    Frame::from_grid(grid, |x, y, &v| {
+        let cell =
+            Cell::colored(char::from_digit(v, 10).unwrap_or('+'), AnsiColors::White);
+
+        if flashes.contains(&Point2::new(x, y)) {
+            cell.bold()
+        } else {
+            cell
+        }
+    })
+}
+
+fn part1(input: &Grid<u32>, steps: usize) -> anyhow::Result<usize> {
+    let mut input = input.clone();
+    let mut worklist = BTreeSet::new();
+
+    let mut total_flashes = 0;
+    for _ in 1..=steps {
+        let flashes = simulate_step(&mut input, &mut worklist);
+
+        total_flashes += flashes.len();
+    }
+
+    Ok(total_flashes)
+}
+
+fn part2(input: &Grid<u32>) -> anyhow::Result<i32> {
+    let mut input = input.clone();
+    let mut worklist = BTreeSet::new();
+
+    for i in 1..=i32::MAX {
+        let flashes = simulate_step(&mut input, &mut worklist);
+
+        if flashes.len() == input.get_height() * input.get_width() {
+            return Ok(i);
+        }
+    }
+
+    bail!("The octopuses never flash simultaneously.")
+}
+
+/// Octopuses flashing step by step, until the puzzle is solved.
+struct Octopuses {
+    grid: Grid<u32>,
+    worklist: BTreeSet<Point2<i32>>,
+    flashes: HashSet<Point2<i32>>,
+    steps: usize,
+    /// Steps of the first puzzle, the second one runs until all flash at once.
+    limit: Option<usize>,
+}
+
+impl Octopuses {
+    fn new(grid: Grid<u32>, puzzle: Puzzle) -> anyhow::Result<Self> {
+        let limit = match puzzle {
+            Puzzle::First => {
+                Some(Params::defaults(Day11::PARAMS, puzzle).get("steps")?)
+            }
+            Puzzle::Second => None,
+        };
+
+        Ok(Self {
+            grid,
+            worklist: BTreeSet::new(),
+            flashes: HashSet::new(),
+            steps: 0,
+            limit,
+        })
+    }
+}
+
+impl Simulation for Octopuses {
+    fn step(&mut self) -> bool {
+        let done = match self.limit {
+            Some(limit) => self.steps == limit,
+            None => {
+                self.flashes.len() == self.grid.get_height() * self.grid.get_width()
+            }
+        };
+        if done {
+            return false;
+        }
+
+        self.flashes = simulate_step(&mut self.grid, &mut self.worklist);
+        self.steps += 1;
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        grid_frame(&self.grid, &self.flashes)
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: Day = Day(11);
+
+    type Input = Grid<u32>;
+    type First = usize;
+    type Second = i32;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        description: "Steps in which the flashes are counted",
+        first: Some("100"),
+        second: None,
+    }];
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<usize> {
+        Self::part1_with(input, &Params::defaults(Self::PARAMS, Puzzle::First))
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> anyhow::Result<usize> {
+        part1(input, params.get("steps")?)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<i32> {
+        part2(input)
+    }
+
+    fn visualize(
+        input: &Self::Input,
+        puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        simulation::record(&mut Octopuses::new(input.clone(), puzzle)?, canvas)
+    }
+
+    fn simulation(
+        input: &Self::Input,
+        puzzle: Puzzle,
+    ) -> anyhow::Result<Box<dyn Simulation + '_>> {
+        Ok(Box::new(Octopuses::new(input.clone(), puzzle)?))
+    }
+}
@@ -0,0 +1,317 @@
+use std::{collections::HashMap, io::Write};
+
+use anyhow::{anyhow, bail, ensure};
+use itertools::Itertools;
+
+use crate::{
+    graph::{DumpDot, Graph, GraphAttribute, NodeId},
+    parse::ParseError,
+    repl::{self, Inspector, Query},
+    visualize::{Canvas, Frame},
+    Day, Puzzle, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaveSize {
+    Large,
+    Small,
+}
+
+pub struct ParseResult {
+    graph: Graph,
+    cave_sizes: GraphAttribute<CaveSize>,
+    names: GraphAttribute<String>,
+}
+
+fn cave_size(name: &str) -> CaveSize {
+    if name.chars().all(|c| c.is_ascii_uppercase()) {
+        CaveSize::Large
+    } else {
+        CaveSize::Small
+    }
+}
+
+fn parse<'a>(input: &'a str) -> anyhow::Result<ParseResult> {
+    let mut result = Graph::new();
+    let mut cave_sizes = GraphAttribute::new();
+
+    let mut name_to_id = HashMap::new();
+    let mut names = GraphAttribute::new();
+
+    let mut get_node_id = |node_name: &'a str, graph: &mut Graph| {
+        if let Some(node_id) = name_to_id.get(node_name) {
+            *node_id
+        } else {
+            let cave_size = cave_size(node_name);
+
+            let node_id = graph.add_node();
+
+            cave_sizes.insert(node_id, cave_size);
+            name_to_id.insert(node_name, node_id);
+            names.insert(node_id, node_name.to_string());
+            node_id
+        }
+    };
+
+    for line in input.trim().lines() {
+        let line = line.trim();
+        let mut parts = line.split('-');
+
+        let error = |message| ParseError::new(input, line, message);
+
+        let start = parts.next().ok_or_else(|| error("Missing start of edge"))?;
+        let end = parts.next().ok_or_else(|| error("Missing end of edge"))?;
+
+        ensure!(
+            parts.next().is_none(),
+            error("Unexpected next part of edge")
+        );
+
+        let start_id = get_node_id(start, &mut result);
+        let end_id = get_node_id(end, &mut result);
+
+        // Paths could go back and forth between two large caves forever.
+        ensure!(
+            cave_size(start) == CaveSize::Small || cave_size(end) == CaveSize::Small,
+            error("Edge between two large caves allows infinitely many paths")
+        );
+
+        result.add_edge(start_id, end_id);
+    }
+
+    Ok(ParseResult {
+        graph: result,
+        cave_sizes,
+        names,
+    })
+}
+
+struct Solver<'a> {
+    graph: &'a Graph,
+    cave_sizes: &'a GraphAttribute<CaveSize>,
+    end_node: NodeId,
+    start_node: NodeId,
+    visited: HashMap<NodeId, u32>,
+    have_visited_small_cave_twice: bool,
+
+    can_visit_single_cave_twice: bool,
+}
+
+impl<'a> Solver<'a> {
+    fn count_paths_recursive(&mut self, node: NodeId) -> u64 {
+        let mut paths = 0;
+
+        if self.cave_sizes[node] == CaveSize::Small {
+            if let Some(number_visited) = self.visited.get_mut(&node) {
+                let max_visit_allowed = if self.can_visit_single_cave_twice
+                    && !self.have_visited_small_cave_twice
+                {
+                    2
+                } else {
+                    1
+                };
+
+                if *number_visited >= max_visit_allowed {
+                    return 0;
+                } else {
+                    *number_visited += 1;
+
+                    if *number_visited == 2 {
+                        self.have_visited_small_cave_twice = true;
+                    }
+                }
+            } else {
+                self.visited.insert(node, 1);
+            }
+        }
+
+        for &neighbor_id in self.graph.get_node(node).neighbors() {
+            if neighbor_id == self.end_node {
+                paths += 1;
+            } else if neighbor_id == self.start_node {
+                paths += 0;
+            } else {
+                paths += self.count_paths_recursive(neighbor_id);
+            }
+        }
+
+        if self.cave_sizes[node] == CaveSize::Small {
+            let number_visited = self.visited.get_mut(&node).unwrap();
+            if *number_visited == 2 {
+                assert!(self.can_visit_single_cave_twice);
+                self.have_visited_small_cave_twice = false;
+            }
+            *number_visited -= 1;
+        }
+
+        paths
+    }
+}
+
+fn solve(
+    input: &ParseResult,
+    can_visit_single_cave_twice: bool,
+) -> anyhow::Result<u64> {
+    let ParseResult {
+        graph,
+        cave_sizes,
+        names,
+    } = input;
+
+    let start_node = names
+        .iter()
+        .find(|&(_, name)| *name == "start")
+        .map(|(id, _)| id)
+        .ok_or(anyhow!("Missing start node"))?;
+    let end_node = names
+        .iter()
+        .find(|&(_, name)| *name == "end")
+        .map(|(id, _)| id)
+        .ok_or(anyhow!("Missing end node"))?;
+
+    let mut solver = Solver {
+        graph,
+        cave_sizes,
+        end_node,
+        start_node,
+        visited: HashMap::new(),
+        have_visited_small_cave_twice: false,
+        can_visit_single_cave_twice,
+    };
+
+    let result = solver.count_paths_recursive(start_node);
+
+    Ok(result)
+}
+
+/// The caves with their connections, which do not change.
+struct Caves<'a>(&'a ParseResult);
+
+impl Caves<'_> {
+    fn find(&self, name: &str) -> anyhow::Result<NodeId> {
+        self.0
+            .names
+            .iter()
+            .find(|&(_, n)| n == name)
+            .map(|(id, _)| id)
+            .ok_or(anyhow!("There is no cave '{}'.", name))
+    }
+
+    fn describe(&self, node: NodeId) -> String {
+        let size = match self.0.cave_sizes[node] {
+            CaveSize::Large => "large",
+            CaveSize::Small => "small",
+        };
+        let neighbors = self
+            .0
+            .graph
+            .get_node(node)
+            .neighbors()
+            .iter()
+            .map(|&n| &self.0.names[n])
+            .join(", ");
+
+        format!("{} ({}): {}", self.0.names[node], size, neighbors)
+    }
+}
+
+impl Inspector for Caves<'_> {
+    fn show(&self, out: &mut dyn Write) -> anyhow::Result<()> {
+        for (node, _) in self.0.names.iter() {
+            writeln!(out, "{}", self.describe(node))?;
+        }
+
+        Ok(())
+    }
+
+    fn reset(&mut self) {}
+
+    fn queries(&self) -> &'static [Query] {
+        &[
+            Query {
+                name: "cave",
+                args: "<cave>",
+                help: "Size and neighbors of a cave",
+            },
+            Query {
+                name: "paths",
+                args: "[twice]",
+                help: "Paths from start to end, visiting a small cave twice",
+            },
+            Query {
+                name: "dot",
+                args: "",
+                help: "The caves as a Graphviz graph",
+            },
+        ]
+    }
+
+    fn query(
+        &self,
+        name: &str,
+        args: &[&str],
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        match name {
+            "cave" => {
+                let cave: String = repl::arg(args, 0, "<cave>")?;
+                writeln!(out, "{}", self.describe(self.find(&cave)?))?;
+            }
+            "paths" => {
+                let twice = match args {
+                    [] => false,
+                    ["twice"] => true,
+                    _ => bail!("Expected `paths` or `paths twice`."),
+                };
+                writeln!(out, "{}", solve(self.0, twice)?)?;
+            }
+            "dot" => {
+                DumpDot::new(&self.0.graph)
+                    .with("name", &self.0.names)
+                    .dump(out)?;
+            }
+            _ => bail!("There is no query '{}'.", name),
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: Day = Day(12);
+
+    type Input = ParseResult;
+    type First = u64;
+    type Second = u64;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u64> {
+        solve(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u64> {
+        solve(input, true)
+    }
+
+    fn visualize(
+        input: &Self::Input,
+        _puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        let mut dot = Vec::new();
+        DumpDot::new(&input.graph)
+            .with("name", &input.names)
+            .dump(&mut dot)?;
+
+        canvas.draw(Frame::Dot(String::from_utf8(dot)?))
+    }
+
+    fn inspector(input: &Self::Input) -> anyhow::Result<Box<dyn Inspector + '_>> {
+        Ok(Box::new(Caves(input)))
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, bail, ensure};
+
+use crate::{
+    geom::{Axis, BoundingBox, Point2},
+    grid::Grid,
+    parse::{self, ParseError},
+    visualize::{Canvas, Cell, Frame},
+    Answer, Day, Puzzle, Solution,
+};
+
+/// Folds the paper up or left along the line where `axis` is `at`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fold {
+    axis: Axis,
+    at: i32,
+}
+
+pub struct ParseResult {
+    points: HashSet<Point2<i32>>,
+    folds: Vec<Fold>,
+}
+
+fn parse_point(text: &str, line: &str) -> anyhow::Result<Point2<i32>> {
+    let mut parts = line.split(',');
+    let error = |message| ParseError::new(text, line, message);
+
+    let x = parts.next().ok_or_else(|| error("Missing x coordinate"))?;
+    let x: i32 = parse::number(text, x)?;
+
+    let y = parts.next().ok_or_else(|| error("Missing y coordinate"))?;
+    let y: i32 = parse::number(text, y)?;
+
+    ensure!(x >= 0 && y >= 0, error("Negative coordinate"));
+
+    Ok(Point2::new(x, y))
+}
+
+fn parse_fold(text: &str, line: &str) -> anyhow::Result<Fold> {
+    let mut parts = line.split_whitespace();
+    let error = |message| ParseError::new(text, line, message);
+
+    ensure!(
+        parts.next().map(|f| f == "fold").unwrap_or(false),
+        error("Missing fold keyword")
+    );
+    ensure!(
+        parts.next().map(|f| f == "along").unwrap_or(false),
+        error("Missing along keyword")
+    );
+
+    let actual_fold = parts.next().ok_or_else(|| error("Missing fold"))?;
+
+    let (dimension, value_str) = actual_fold
+        .split_once('=')
+        .ok_or_else(|| ParseError::new(text, actual_fold, "Missing equal sign"))?;
+
+    let value: i32 = parse::number(text, value_str)?;
+    ensure!(
+        value >= 0,
+        ParseError::new(text, value_str, "Negative fold")
+    );
+
+    let axis = match dimension {
+        "x" => Axis::X,
+        "y" => Axis::Y,
+        _ => bail!(ParseError::new(
+            text,
+            dimension,
+            format!("Invalid dimensional axis '{}'", dimension)
+        )),
+    };
+
+    Ok(Fold { axis, at: value })
+}
+
+fn parse(input: &str) -> anyhow::Result<ParseResult> {
+    let mut parts = input.trim().split("\n\n");
+
+    let points = parts
+        .next()
+        .ok_or_else(|| ParseError::end(input, "Missing points"))?;
+
+    let points = points
+        .trim()
+        .lines()
+        .map(|line| parse_point(input, line))
+        .collect::<Result<_, _>>()?;
+
+    let folds = parts
+        .next()
+        .ok_or_else(|| ParseError::end(input, "Missing fold instructions"))?;
+    let folds = folds
+        .trim()
+        .lines()
+        .map(|l| parse_fold(input, l))
+        .collect::<Result<_, _>>()?;
+
+    Ok(ParseResult { points, folds })
+}
+
+fn apply_fold(points: &HashSet<Point2<i32>>, f: Fold) -> HashSet<Point2<i32>> {
+    // Mirrors without overflowing, as both the point and the fold are positive.
+    let func = |&p: &Point2<i32>| -> Point2<i32> {
+        let mut p = p;
+        let c = &mut p[f.axis];
+        if *c > f.at {
+            *c = f.at - (*c - f.at);
+        }
+        p
+    };
+
+    points.iter().map(func).collect()
+}
+
+fn part1(input: &ParseResult) -> anyhow::Result<usize> {
+    let ParseResult { points, folds } = input;
+
+    let fold = *folds.first().ok_or(anyhow!("Missing fold instructions"))?;
+    let folded_points = apply_fold(points, fold);
+
+    Ok(folded_points.len())
+}
+
+/// Most points of the paper which is shown, so huge coordinates are an error
+/// instead of an allocation failure.
+const MAX_GRID_SIZE: usize = 1 << 26;
+
+/// Grid with the points of the paper set.
+fn points_grid(points: &HashSet<Point2<i32>>) -> anyhow::Result<Grid<bool>> {
+    let bounds = BoundingBox::from_points(points.iter().copied())
+        .ok_or(anyhow!("Missing points"))?;
+
+    ensure!(
+        bounds.min.x() >= 0 && bounds.min.y() >= 0,
+        "Points are folded to negative coordinates."
+    );
+
+    let (width, height) = (bounds.max.x() as usize + 1, bounds.max.y() as usize + 1);
+    ensure!(
+        width
+            .checked_mul(height)
+            .is_some_and(|size| size <= MAX_GRID_SIZE),
+        "The paper of {}x{} points is too large, at most {} points are supported.",
+        width,
+        height,
+        MAX_GRID_SIZE
+    );
+
+    let mut grid = Grid::new(width, height, false);
+    for &p in points {
+        grid[p] = true;
+    }
+
+    Ok(grid)
+}
+
+fn part2(input: &ParseResult) -> anyhow::Result<Answer> {
+    let ParseResult { points, folds } = input;
+    let mut points = points.clone();
+
+    for &fold in folds {
+        points = apply_fold(&points, fold);
+    }
+
+    let rows = points_grid(&points)?
+        .rows()
+        .map(|row| row.iter().map(|&t| if t { '#' } else { '.' }).collect())
+        .collect();
+
+    Ok(Answer::Grid(rows))
+}
+
+/// Draws the paper before and after every fold of the puzzle.
+fn visualize(
+    input: &ParseResult,
+    puzzle: Puzzle,
+    canvas: &mut dyn Canvas,
+) -> anyhow::Result<()> {
+    let ParseResult { points, folds } = input;
+    let folds = match puzzle {
+        Puzzle::First => &folds[..folds.len().min(1)],
+        Puzzle::Second => &folds[..],
+    };
+
+    let frame = |points: &HashSet<Point2<i32>>| -> anyhow::Result<Frame> {
+        let grid = points_grid(points)?;
+        Ok(Frame::from_grid(&grid, |_, _, &t| {
+            Cell::plain(if t { '#' } else { '.' })
+        }))
+    };
+
+    let mut points = points.clone();
+    canvas.draw(frame(&points)?)?;
+
+    for &fold in folds {
+        points = apply_fold(&points, fold);
+        canvas.draw(frame(&points)?)?;
+    }
+
+    Ok(())
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: Day = Day(13);
+
+    type Input = ParseResult;
+    type First = usize;
+    type Second = Answer;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Answer> {
+        part2(input)
+    }
+
+    fn visualize(
+        input: &Self::Input,
+        puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        visualize(input, puzzle, canvas)
+    }
+}
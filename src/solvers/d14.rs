@@ -0,0 +1,280 @@
+use std::{collections::HashMap, hash::Hash, io::Write};
+
+use anyhow::{anyhow, bail, ensure};
+use itertools::Itertools;
+use num::{BigUint, One};
+
+use crate::{
+    parse::ParseError,
+    repl::{self, Inspector, Query},
+    Day, Param, Params, Puzzle, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Element(char);
+
+#[derive(Debug)]
+pub struct ParseResult {
+    starting_sequence: Vec<Element>,
+    rules: HashMap<[Element; 2], Element>,
+}
+
+fn parse_rule(text: &str, line: &str) -> anyhow::Result<([Element; 2], Element)> {
+    let mut parts = line.trim().split("->");
+    let error = |message| ParseError::new(text, line, message);
+
+    let pair = parts
+        .next()
+        .ok_or_else(|| error("Missing starting pair in rule"))?;
+
+    let mut elements = pair.trim().chars().map(Element);
+    let first_element = elements
+        .next()
+        .ok_or_else(|| error("Missing starting pair"))?;
+    let second_element = elements
+        .next()
+        .ok_or_else(|| error("Missing second element in starting pair"))?;
+    ensure!(
+        elements.next().is_none(),
+        ParseError::new(text, pair.trim(), "Too many starting elements in rule")
+    );
+
+    let inserted_element = parts
+        .next()
+        .ok_or_else(|| error("Missing insertion element in rule"))?
+        .trim();
+
+    let mut inserted = inserted_element.chars();
+    let inserted_element = match (inserted.next(), inserted.next()) {
+        (Some(inserted_element), None) => inserted_element,
+        _ => bail!(ParseError::new(
+            text,
+            inserted_element,
+            "There can only be a single insertion element per rule"
+        )),
+    };
+
+    Ok(([first_element, second_element], Element(inserted_element)))
+}
+
+fn parse(input: &str) -> anyhow::Result<ParseResult> {
+    let mut parts = input.trim().split("\n\n");
+
+    let sequence = parts
+        .next()
+        .ok_or_else(|| ParseError::end(input, "Can't find input sequence"))?;
+    let sequence = sequence.trim().chars().map(Element).collect();
+
+    let rules = parts
+        .next()
+        .ok_or_else(|| ParseError::end(input, "Can't find insertion rules"))?;
+
+    let rules = rules
+        .trim()
+        .lines()
+        .map(|line| parse_rule(input, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok(ParseResult {
+        starting_sequence: sequence,
+        rules,
+    })
+}
+
+type ElementCount = HashMap<Element, BigUint>;
+
+fn add_count<K: Eq + Hash>(map: &mut HashMap<K, BigUint>, key: K, n: &BigUint) {
+    *map.entry(key).or_default() += n;
+}
+
+/// The polymer as the counts of its pairs, after some steps of pair insertion.
+/// Counts grow exponentially with the steps, so they are not bounded.
+struct Polymer<'a> {
+    input: &'a ParseResult,
+    initial: HashMap<[Element; 2], BigUint>,
+    pairs: HashMap<[Element; 2], BigUint>,
+    steps: usize,
+}
+
+impl<'a> Polymer<'a> {
+    fn new(input: &'a ParseResult) -> Self {
+        let mut pairs = HashMap::new();
+        for pair in input.starting_sequence.windows(2) {
+            add_count(&mut pairs, [pair[0], pair[1]], &BigUint::one());
+        }
+
+        Self {
+            input,
+            initial: pairs.clone(),
+            pairs,
+            steps: 0,
+        }
+    }
+
+    /// Every element is the first of a pair, except for the last one of the
+    /// polymer which never changes.
+    fn elements(&self) -> ElementCount {
+        let mut elements = ElementCount::new();
+        for (&[first, _], count) in &self.pairs {
+            add_count(&mut elements, first, count);
+        }
+        if let Some(&last) = self.input.starting_sequence.last() {
+            add_count(&mut elements, last, &BigUint::one());
+        }
+
+        elements
+    }
+
+    /// The most minus the least common element.
+    fn score(&self) -> anyhow::Result<BigUint> {
+        let elements = self.elements();
+        let (min, max) = elements
+            .values()
+            .minmax()
+            .into_option()
+            .ok_or(anyhow!("The polymer is empty."))?;
+
+        Ok(max - min)
+    }
+}
+
+fn solve(input: &ParseResult, steps: usize) -> anyhow::Result<BigUint> {
+    let mut polymer = Polymer::new(input);
+    polymer.step(steps)?;
+
+    polymer.score()
+}
+
+impl Inspector for Polymer<'_> {
+    fn show(&self, out: &mut dyn Write) -> anyhow::Result<()> {
+        let elements: BigUint = self.elements().values().sum();
+        writeln!(out, "Step {}, {} elements", self.steps, elements)?;
+
+        for (&[first, second], count) in
+            self.pairs.iter().sorted_by_key(|(p, _)| (p[0].0, p[1].0))
+        {
+            writeln!(out, "{}{}: {}", first.0, second.0, count)?;
+        }
+
+        Ok(())
+    }
+
+    fn step(&mut self, steps: usize) -> anyhow::Result<()> {
+        for _ in 0..steps {
+            let mut pairs = HashMap::new();
+
+            for (&[first, second], count) in &self.pairs {
+                match self.input.rules.get(&[first, second]) {
+                    Some(&inserted) => {
+                        add_count(&mut pairs, [first, inserted], count);
+                        add_count(&mut pairs, [inserted, second], count);
+                    }
+                    None => add_count(&mut pairs, [first, second], count),
+                }
+            }
+
+            self.pairs = pairs;
+            self.steps += 1;
+        }
+
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.pairs = self.initial.clone();
+        self.steps = 0;
+    }
+
+    fn queries(&self) -> &'static [Query] {
+        &[
+            Query {
+                name: "elements",
+                args: "",
+                help: "Count of every element, most common first",
+            },
+            Query {
+                name: "pair",
+                args: "<pair>",
+                help: "Count of a pair, e.g. `pair NC`",
+            },
+            Query {
+                name: "score",
+                args: "",
+                help: "Most minus least common element, the answer after 10 or 40 steps",
+            },
+        ]
+    }
+
+    fn query(
+        &self,
+        name: &str,
+        args: &[&str],
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        match name {
+            "elements" => {
+                let elements = self.elements();
+                for (element, count) in elements
+                    .iter()
+                    .sorted_by_key(|&(e, count)| (std::cmp::Reverse(count), e.0))
+                {
+                    writeln!(out, "{}: {}", element.0, count)?;
+                }
+            }
+            "pair" => {
+                let pair: String = repl::arg(args, 0, "<pair>")?;
+                let pair = match pair.chars().collect::<Vec<_>>()[..] {
+                    [first, second] => [Element(first), Element(second)],
+                    _ => bail!("A pair has two elements, not '{}'.", pair),
+                };
+                let count = self.pairs.get(&pair).cloned().unwrap_or_default();
+                writeln!(out, "{}", count)?;
+            }
+            "score" => writeln!(out, "{}", self.score()?)?,
+            _ => bail!("There is no query '{}'.", name),
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: Day = Day(14);
+
+    type Input = ParseResult;
+    type First = BigUint;
+    type Second = BigUint;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        description: "Steps of pair insertion",
+        first: Some("10"),
+        second: Some("40"),
+    }];
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<BigUint> {
+        Self::part1_with(input, &Params::defaults(Self::PARAMS, Puzzle::First))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<BigUint> {
+        Self::part2_with(input, &Params::defaults(Self::PARAMS, Puzzle::Second))
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> anyhow::Result<BigUint> {
+        solve(input, params.get("steps")?)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> anyhow::Result<BigUint> {
+        solve(input, params.get("steps")?)
+    }
+
+    fn inspector(input: &Self::Input) -> anyhow::Result<Box<dyn Inspector + '_>> {
+        Ok(Box::new(Polymer::new(input)))
+    }
+}
@@ -3,30 +3,17 @@ use std::collections::BinaryHeap;
 use anyhow::anyhow;
 use itertools::iproduct;
 
-use crate::grid::Grid;
+use crate::{geom::Point2, grid::Grid, Day, Solution};
 
 fn parse(input: &str) -> anyhow::Result<Grid<u32>> {
-    let height = input.trim().lines().count();
-    let data = input
-        .trim()
-        .lines()
-        .flat_map(|line| {
-            line.trim().chars().map(|digit| {
-                digit
-                    .to_digit(10)
-                    .ok_or(anyhow!("'{}' is not a digit.", digit))
-            })
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    let width = data.len() / height;
-
-    Ok(Grid::from_rows_columns(width, height, data))
+    let grid = Grid::parse(input, input, |digit| digit.to_digit(10))?;
+
+    Ok(grid)
 }
 
-#[derive(Debug, Eq, Ord)]
+#[derive(Debug, Eq)]
 struct HeapEntry {
-    coord: (i32, i32),
+    coord: Point2<i32>,
     score: u32,
 }
 
@@ -38,15 +25,15 @@ impl PartialEq for HeapEntry {
 
 impl PartialOrd for HeapEntry {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        // We want a min heap, so we reverse the ordering.
-        Some(self.score.cmp(&other.score).reverse())
+        Some(self.cmp(other))
     }
 }
 
-const NEIGHBORS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-
-fn neighbors((x, y): (i32, i32)) -> impl Iterator<Item = (i32, i32)> {
-    NEIGHBORS.into_iter().map(move |(dx, dy)| (x + dx, y + dy))
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // We want a min heap, so we reverse the ordering.
+        self.score.cmp(&other.score).reverse()
+    }
 }
 
 // dijkstras algorithm on a 2D grid
@@ -55,23 +42,25 @@ fn shortest_path(grid: &Grid<u32>) -> anyhow::Result<u32> {
 
     let mut queue = BinaryHeap::new();
     queue.push(HeapEntry {
-        coord: (0, 0),
+        coord: Point2::origin(),
         score: 0,
     });
     scores[(0, 0)] = Some(0);
 
     // We want to get to the bottom right.
-    let end_coord = (grid.get_width() as i32 - 1, grid.get_height() as i32 - 1);
+    let end_coord =
+        Point2::new(grid.get_width() as i32 - 1, grid.get_height() as i32 - 1);
 
     while let Some(HeapEntry { coord, score }) = queue.pop() {
         if coord == end_coord {
             break;
         }
 
-        for (neighbor_coord, _) in neighbors(coord)
-            .filter_map(|(x, y)| grid.get(x, y).map(|&s| ((x, y), s)))
+        for (neighbor_coord, risk) in coord
+            .neighbors()
+            .filter_map(|n| grid.get_point(n).map(|&s| (n, s)))
         {
-            let new_neighbor_score = score + grid[neighbor_coord];
+            let new_neighbor_score = score + risk;
 
             let is_better_score = scores[neighbor_coord]
                 .filter(|&current_score| current_score <= new_neighbor_score)
@@ -86,16 +75,10 @@ fn shortest_path(grid: &Grid<u32>) -> anyhow::Result<u32> {
         }
     }
 
-    Ok(scores[end_coord].unwrap())
+    scores[end_coord].ok_or(anyhow!("The bottom right can not be reached."))
 }
 
-pub fn part1(text: &str) -> anyhow::Result<u32> {
-    let grid = parse(text)?;
-    shortest_path(&grid)
-}
-
-pub fn part2(text: &str) -> anyhow::Result<u32> {
-    let small_grid = parse(text)?;
+fn part2(small_grid: &Grid<u32>) -> anyhow::Result<u32> {
     let mut larger_grid =
         Grid::new(small_grid.get_width() * 5, small_grid.get_height() * 5, 0);
 
@@ -107,9 +90,31 @@ pub fn part2(text: &str) -> anyhow::Result<u32> {
             );
 
             larger_grid[coord_in_large_grid] =
-                (score + gx as u32 + gy as u32 - 1) % 9 + 1;
+                (score + gx as u32 + gy as u32 + 8) % 9 + 1;
         }
     }
 
     shortest_path(&larger_grid)
 }
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: Day = Day(15);
+
+    type Input = Grid<u32>;
+    type First = u32;
+    type Second = u32;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u32> {
+        shortest_path(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u32> {
+        part2(input)
+    }
+}
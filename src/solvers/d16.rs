@@ -0,0 +1,340 @@
+use anyhow::{anyhow, bail};
+use bitvec::prelude::*;
+use itertools::Itertools;
+
+use crate::{
+    parse::{offset_of, ParseError},
+    Day, Solution,
+};
+
+#[derive(Debug)]
+pub enum PacketType {
+    Literal(u64),
+    Operator {
+        op: PacketOp,
+        subpackets: Vec<Packet>,
+    },
+}
+
+#[derive(Debug)]
+pub struct Packet {
+    version: u32,
+    packet_type: PacketType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketOp {
+    Sum,
+    Product,
+    Min,
+    Max,
+    GreaterThan,
+    LessThan,
+    Equal,
+}
+
+type Bits = BitSlice<Msb0, u8>;
+
+/// Packets which are nested deeper are rejected, so that parsing and evaluating
+/// them can not overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+/// The hex encoded transmission, used to report where in the input parsing failed.
+struct Source<'a> {
+    text: &'a str,
+    hex: &'a str,
+    len: usize,
+}
+
+impl Source<'_> {
+    fn error(&self, rest: &Bits, message: &str) -> anyhow::Error {
+        let bit = self.len - rest.len();
+        let offset = offset_of(self.text, self.hex) + bit / 4;
+
+        ParseError::at(
+            self.text,
+            offset..offset + 1,
+            format!("{} (bit {})", message, bit),
+        )
+        .into()
+    }
+
+    /// Splits off the first `n` bits.
+    fn split<'b>(
+        &self,
+        bits: &'b Bits,
+        n: usize,
+    ) -> anyhow::Result<(&'b Bits, &'b Bits)> {
+        if bits.len() < n {
+            return Err(self.error(bits, "Unexpected end of transmission"));
+        }
+
+        Ok(bits.split_at(n))
+    }
+}
+
+fn parse_packet_op(val: u32) -> Option<PacketOp> {
+    use PacketOp::*;
+
+    let op = match val {
+        0 => Sum,
+        1 => Product,
+        2 => Min,
+        3 => Max,
+        5 => GreaterThan,
+        6 => LessThan,
+        7 => Equal,
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+fn parse_value(bits: &Bits) -> u32 {
+    let mut v = 0;
+
+    for i in 0..bits.len() {
+        if bits[i] {
+            v |= 1 << (bits.len() - 1 - i);
+        }
+    }
+
+    v
+}
+
+fn parse_literal_packet<'a>(
+    source: &Source,
+    mut bits: &'a Bits,
+) -> anyhow::Result<(PacketType, &'a Bits)> {
+    let mut result: u64 = 0;
+    let result_view: &mut BitSlice<Msb0, u64> = result.view_bits_mut();
+    let mut result_size = 0;
+
+    loop {
+        let (group, rest) = source.split(bits, 5)?;
+
+        if result_size == u64::BITS as usize {
+            return Err(source.error(bits, "Literal does not fit into 64 bits"));
+        }
+
+        result_view[result_size..result_size + 4].clone_from_bitslice(&group[1..]);
+        result_size += 4;
+        bits = rest;
+
+        if !group[0] {
+            break;
+        }
+    }
+
+    result >>= u64::BITS as usize - result_size;
+
+    Ok((PacketType::Literal(result), bits))
+}
+
+fn parse_operator_packet<'a>(
+    source: &Source,
+    bits: &'a Bits,
+    op: PacketOp,
+    depth: usize,
+) -> anyhow::Result<(PacketType, &'a Bits)> {
+    let (length_type_id, bits) = source.split(bits, 1)?;
+
+    let mut subpackets = Vec::new();
+
+    let remaining_bits;
+
+    if !length_type_id[0] {
+        let (length_of_subpackets, mut bits) = source.split(bits, 15)?;
+        let length_of_subpackets = parse_value(length_of_subpackets) as usize;
+
+        let remaining_size = bits.len();
+
+        if remaining_size < length_of_subpackets {
+            return Err(
+                source.error(bits, "Subpackets are longer than the transmission")
+            );
+        }
+
+        while remaining_size - bits.len() < length_of_subpackets {
+            let (packet, remaining) = parse_packet(source, bits, depth + 1)?;
+            bits = remaining;
+            subpackets.push(packet);
+        }
+
+        if remaining_size - bits.len() > length_of_subpackets {
+            return Err(source.error(bits, "Subpackets exceed their length"));
+        }
+
+        remaining_bits = bits;
+    } else {
+        let (number_of_subpackets, mut bits) = source.split(bits, 11)?;
+        let number_of_subpackets = parse_value(number_of_subpackets);
+
+        for _ in 0..number_of_subpackets {
+            let (packet, remaining) = parse_packet(source, bits, depth + 1)?;
+            bits = remaining;
+            subpackets.push(packet);
+        }
+
+        remaining_bits = bits;
+    }
+
+    let valid = match op {
+        PacketOp::Sum | PacketOp::Product => true,
+        PacketOp::Min | PacketOp::Max => !subpackets.is_empty(),
+        PacketOp::GreaterThan | PacketOp::LessThan | PacketOp::Equal => {
+            subpackets.len() == 2
+        }
+    };
+
+    if !valid {
+        return Err(source.error(
+            remaining_bits,
+            &format!("Invalid number of subpackets for {:?}", op),
+        ));
+    }
+
+    let packet_type = PacketType::Operator { op, subpackets };
+
+    Ok((packet_type, remaining_bits))
+}
+
+fn parse_packet<'a>(
+    source: &Source,
+    bits: &'a Bits,
+    depth: usize,
+) -> anyhow::Result<(Packet, &'a Bits)> {
+    if depth > MAX_DEPTH {
+        return Err(source.error(bits, "Packets are nested too deeply"));
+    }
+
+    let (version, rest) = source.split(bits, 3)?;
+    let version = parse_value(version);
+
+    let (type_id, rest) = source.split(rest, 3)?;
+    let type_id = parse_value(type_id);
+
+    let (packet_type, remaining) = if type_id == 4 {
+        parse_literal_packet(source, rest)?
+    } else {
+        let op = parse_packet_op(type_id)
+            .ok_or_else(|| source.error(bits, "Invalid packet type"))?;
+        parse_operator_packet(source, rest, op, depth)?
+    };
+
+    let packet = Packet {
+        version,
+        packet_type,
+    };
+    Ok((packet, remaining))
+}
+
+fn parse_hex_to_bitvec(text: &str, input: &str) -> anyhow::Result<BitVec<Msb0, u8>> {
+    let digits = input
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(16).ok_or_else(|| {
+                ParseError::new(
+                    text,
+                    &input[i..i + c.len_utf8()],
+                    format!("Invalid hex digit '{}'", c),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if digits.len() % 2 != 0 {
+        bail!(ParseError::new(text, input, "Odd number of hex digits"));
+    }
+
+    let bytes = digits
+        .into_iter()
+        .tuples()
+        .map(|(first_digit, second_digit)| (first_digit << 4 | second_digit) as u8)
+        .collect();
+
+    Ok(bytes)
+}
+
+fn parse(input: &str) -> anyhow::Result<Packet> {
+    let hex = input.trim();
+    let bitvec = parse_hex_to_bitvec(input, hex)?;
+
+    let source = Source {
+        text: input,
+        hex,
+        len: bitvec.len(),
+    };
+
+    let (packet, _) = parse_packet(&source, &bitvec, 0)?;
+    Ok(packet)
+}
+
+fn sum_version_numbers(packet: &Packet) -> u64 {
+    let mut s: u64 = packet.version as _;
+
+    if let PacketType::Operator { subpackets, .. } = &packet.packet_type {
+        s += subpackets.iter().map(sum_version_numbers).sum::<u64>();
+    }
+
+    s
+}
+
+fn overflow() -> anyhow::Error {
+    anyhow!("The value of the packet does not fit into 64 bits.")
+}
+
+fn eval_packet(packet: &Packet) -> anyhow::Result<u64> {
+    use PacketOp::*;
+
+    let (op, subpackets) = match &packet.packet_type {
+        PacketType::Literal(l) => return Ok(*l),
+        PacketType::Operator { op, subpackets } => (op, subpackets),
+    };
+
+    let values = subpackets
+        .iter()
+        .map(eval_packet)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The number of subpackets is checked while parsing.
+    let result = match op {
+        Sum => values
+            .into_iter()
+            .try_fold(0u64, u64::checked_add)
+            .ok_or_else(overflow)?,
+        Product => values
+            .into_iter()
+            .try_fold(1u64, u64::checked_mul)
+            .ok_or_else(overflow)?,
+        Max => values.into_iter().max().unwrap_or_default(),
+        Min => values.into_iter().min().unwrap_or_default(),
+        GreaterThan => (values[0] > values[1]) as u64,
+        LessThan => (values[0] < values[1]) as u64,
+        Equal => (values[0] == values[1]) as u64,
+    };
+
+    Ok(result)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: Day = Day(16);
+
+    type Input = Packet;
+    type First = u64;
+    type Second = u64;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u64> {
+        Ok(sum_version_numbers(input))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u64> {
+        eval_packet(input)
+    }
+}
@@ -0,0 +1,156 @@
+use std::sync::LazyLock;
+
+use anyhow::ensure;
+use itertools::iproduct;
+use regex::Regex;
+
+use crate::{
+    geom::AxisRange,
+    parse::{self, ParseError},
+    Day, Solution,
+};
+
+#[derive(Debug)]
+pub struct TargetArea {
+    x_range: AxisRange<i32>,
+    y_range: AxisRange<i32>,
+}
+
+impl TargetArea {
+    fn contains(&self, x: i64, y: i64) -> bool {
+        self.x_range.map(i64::from).contains(x)
+            && self.y_range.map(i64::from).contains(y)
+    }
+}
+
+fn parse(input: &str) -> anyhow::Result<TargetArea> {
+    static INPUT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"target area: x=(?P<x_start>-?\d+)..(?P<x_end>-?\d+), y=(?P<y_start>-?\d+)..(?P<y_end>-?\d+)").unwrap()
+    });
+
+    let text = input.trim();
+    let cap = INPUT_REGEX
+        .captures(text)
+        .ok_or_else(|| ParseError::new(input, text, "Invalid input format"))?;
+
+    let number = |name| {
+        parse::number::<i32>(input, cap.name(name).map_or("", |m| m.as_str()))
+    };
+
+    let x_start = number("x_start")?;
+    let x_end = number("x_end")?;
+    let y_start = number("y_start")?;
+    let y_end = number("y_end")?;
+
+    ensure!(
+        0 < x_start && x_start <= x_end,
+        ParseError::new(
+            input,
+            parse::spanning(input, &cap["x_start"], &cap["x_end"]),
+            "The target area has to be right of the launcher"
+        )
+    );
+    ensure!(
+        y_start <= y_end && y_end < 0,
+        ParseError::new(
+            input,
+            parse::spanning(input, &cap["y_start"], &cap["y_end"]),
+            "The target area has to be below the launcher"
+        )
+    );
+
+    Ok(TargetArea {
+        x_range: AxisRange::new(x_start, x_end),
+        y_range: AxisRange::new(y_start, y_end),
+    })
+}
+
+fn part1(input: &TargetArea) -> anyhow::Result<i64> {
+    let max_speed = i64::from(max_y_start_vel(input.y_range));
+
+    let height = max_speed * (max_speed + 1) / 2;
+
+    Ok(height)
+}
+
+/// The target area is below the launcher, which is checked by `parse`.
+fn max_y_start_vel(y_target_range: AxisRange<i32>) -> i32 {
+    let y_start = y_target_range.start;
+
+    -(y_start + 1)
+}
+
+fn get_x_start_vel_range(target_x_range: AxisRange<i32>) -> AxisRange<i32> {
+    // The smallest velocity which still reaches the target, x grows by the
+    // velocity which decreases by one each step.
+    let mut x: i64 = 0;
+    let mut i = 1;
+
+    while x + i64::from(i) < target_x_range.start.into() {
+        x += i64::from(i);
+        i += 1;
+    }
+
+    AxisRange::new(i, target_x_range.end)
+}
+
+fn get_y_start_vel_range(target_y_range: AxisRange<i32>) -> AxisRange<i32> {
+    AxisRange::new(target_y_range.start, max_y_start_vel(target_y_range))
+}
+
+fn simulate_shot(x_vel: i32, y_vel: i32, target: &TargetArea) -> bool {
+    let (mut x_vel, mut y_vel) = (i64::from(x_vel), i64::from(y_vel));
+    let mut pos_x: i64 = 0;
+    let mut pos_y: i64 = 0;
+
+    loop {
+        if pos_x > target.x_range.end.into() || pos_y < target.y_range.start.into() {
+            return false;
+        }
+
+        if target.contains(pos_x, pos_y) {
+            return true;
+        }
+
+        pos_x += x_vel;
+        pos_y += y_vel;
+        x_vel = i64::max(0, x_vel - 1);
+        y_vel -= 1;
+    }
+}
+
+fn part2(area: &TargetArea) -> anyhow::Result<usize> {
+    let &TargetArea { x_range, y_range } = area;
+
+    let start_x_vel_range = get_x_start_vel_range(x_range);
+    let start_y_vel_range = get_y_start_vel_range(y_range);
+
+    let shots = iproduct!(start_x_vel_range, start_y_vel_range)
+        .filter(|&(x, y)| simulate_shot(x, y, area));
+
+    let n = shots.count();
+
+    Ok(n)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: Day = Day(17);
+
+    type Input = TargetArea;
+    type First = i64;
+    type Second = usize;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<usize> {
+        part2(input)
+    }
+}
@@ -1,20 +1,29 @@
 use std::fmt;
 
-use anyhow::anyhow;
+use anyhow::{anyhow, ensure};
 use itertools::iproduct;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{digit1, multispace1},
+    character::complete::{multispace1, satisfy},
     multi::separated_list1,
     sequence::tuple,
     Parser,
 };
 
+use crate::{
+    parse::{offset_of, ParseError},
+    Day, Solution,
+};
+
 type IResult<'src, T> = nom::IResult<&'src str, T>;
 
+/// Numbers which are nested deeper are rejected, so that parsing them can not
+/// overflow the stack.
+const MAX_DEPTH: usize = 32;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum SFNumber {
+pub enum SFNumber {
     Literal(u32),
     Pair(Box<SFNumber>, Box<SFNumber>),
 }
@@ -49,8 +58,8 @@ impl fmt::Display for SFNumber {
 }
 
 fn parse_literal(input: &str) -> IResult<'_, SFNumber> {
-    digit1
-        .map(|num: &str| SFNumber::Literal(num.parse::<u32>().unwrap()))
+    satisfy(|c| c.is_ascii_digit())
+        .map(|digit| SFNumber::Literal(digit as u32 - '0' as u32))
         .parse(input)
 }
 
@@ -74,10 +83,54 @@ fn parse_all_sf_numbers(input: &str) -> IResult<'_, Vec<SFNumber>> {
     separated_list1(multispace1, parse_sf_number).parse(input)
 }
 
+/// Checks the nesting of the brackets before the recursive parser sees them.
+fn check_depth(input: &str) -> anyhow::Result<()> {
+    let mut depth = 0;
+
+    for (i, c) in input.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = usize::saturating_sub(depth, 1),
+            _ => continue,
+        }
+
+        ensure!(
+            depth <= MAX_DEPTH,
+            ParseError::at(
+                input,
+                i..i + 1,
+                format!(
+                    "Snailfish number is nested deeper than {} pairs",
+                    MAX_DEPTH
+                )
+            )
+        );
+    }
+
+    Ok(())
+}
+
 fn parse(input: &str) -> anyhow::Result<Vec<SFNumber>> {
-    parse_all_sf_numbers(input)
-        .map_err(|_| anyhow!("Invalid input"))
-        .map(|(_, result)| result)
+    check_depth(input)?;
+
+    let (rest, numbers) = parse_all_sf_numbers(input).map_err(|e| match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => {
+            let offset = offset_of(input, e.input);
+            let len = e.input.chars().next().map_or(0, char::len_utf8);
+
+            ParseError::at(input, offset..offset + len, "Invalid snailfish number")
+        }
+        nom::Err::Incomplete(_) => {
+            ParseError::end(input, "Incomplete snailfish number")
+        }
+    })?;
+
+    ensure!(
+        rest.trim().is_empty(),
+        ParseError::new(input, rest.trim(), "Unexpected input")
+    );
+
+    Ok(numbers)
 }
 
 fn explode(
@@ -134,30 +187,32 @@ fn reduce(sf: &mut SFNumber) {
     while explode(sf, 0, None, None) || split(sf) {}
 }
 
-fn magnitude(sf: &SFNumber) -> u32 {
+fn magnitude(sf: &SFNumber) -> anyhow::Result<u64> {
     match sf {
-        SFNumber::Literal(value) => *value,
-        SFNumber::Pair(lhs, rhs) => 3 * magnitude(lhs) + 2 * magnitude(rhs),
+        SFNumber::Literal(value) => Ok((*value).into()),
+        SFNumber::Pair(lhs, rhs) => magnitude(lhs)?
+            .checked_mul(3)
+            .zip(magnitude(rhs)?.checked_mul(2))
+            .and_then(|(l, r)| l.checked_add(r))
+            .ok_or(anyhow!("The magnitude does not fit into 64 bits.")),
     }
 }
 
-pub fn part1(text: &str) -> anyhow::Result<u32> {
-    let input = parse(text)?;
+fn part1(input: &[SFNumber]) -> anyhow::Result<u64> {
     let sum = input
-        .into_iter()
+        .iter()
+        .cloned()
         .reduce(|acc, item| {
             let mut sum = acc.add(item);
             reduce(&mut sum);
             sum
         })
-        .expect("Parse would have failed, if empty");
-    Ok(magnitude(&sum))
+        .ok_or(anyhow!("There are no numbers to add."))?;
+    magnitude(&sum)
 }
 
-pub fn part2(text: &str) -> anyhow::Result<u32> {
-    let input = parse(text)?;
-
-    let result = iproduct!(input.iter().enumerate(), input.iter().enumerate())
+fn part2(input: &[SFNumber]) -> anyhow::Result<u64> {
+    let magnitudes = iproduct!(input.iter().enumerate(), input.iter().enumerate())
         .filter(|((i1, _), (i2, _))| i1 != i2)
         .map(|((_, l), (_, r))| (l, r))
         .map(|(l, r)| {
@@ -165,7 +220,32 @@ pub fn part2(text: &str) -> anyhow::Result<u32> {
             reduce(&mut sum);
             magnitude(&sum)
         })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    magnitudes
+        .into_iter()
         .max()
-        .unwrap();
-    Ok(result)
+        .ok_or(anyhow!("At least two numbers are required."))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: Day = Day(18);
+
+    type Input = Vec<SFNumber>;
+    type First = u64;
+    type Second = u64;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u64> {
+        part2(input)
+    }
 }
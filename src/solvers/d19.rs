@@ -0,0 +1,486 @@
+use std::{cmp::Ordering, collections::HashSet, io::Write};
+
+use anyhow::{anyhow, bail, ensure};
+use itertools::iproduct;
+use nalgebra::{Matrix3, Matrix4, Vector3, Vector4};
+
+use crate::{
+    geom::Point3,
+    parse::{self, ParseError},
+    repl::{self, Inspector, Query},
+    Day, Solution,
+};
+
+/// Beacons are detected at most this far away from their scanner in every axis.
+const SCANNER_RANGE: i32 = 1000;
+
+fn parse_point(text: &str, line: &str) -> anyhow::Result<Vector3<i32>> {
+    let line = line.trim();
+    let coords = line
+        .split(',')
+        .map(|v| parse::number::<i32>(text, v.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (x, y, z) = match coords.as_slice() {
+        &[x, y, z] => (x, y, z),
+        _ => bail!(ParseError::new(
+            text,
+            line,
+            format!("Expected three coordinates, found {}", coords.len())
+        )),
+    };
+
+    ensure!(
+        coords.iter().all(|c| c.abs() <= SCANNER_RANGE),
+        ParseError::new(
+            text,
+            line,
+            format!("Beacon is out of the scanner range of {}", SCANNER_RANGE)
+        )
+    );
+
+    Ok(Vector3::new(x, y, z))
+}
+
+fn parse_scanner_points(
+    text: &str,
+    scanner: &str,
+) -> anyhow::Result<Vec<Vector3<i32>>> {
+    scanner
+        .trim()
+        .lines()
+        .skip(1)
+        .map(|line| parse_point(text, line))
+        .collect()
+}
+
+fn parse(input: &str) -> anyhow::Result<Vec<Vec<Vector3<i32>>>> {
+    input
+        .trim()
+        .split("\n\n")
+        .map(|scanner| parse_scanner_points(input, scanner))
+        .collect()
+}
+
+fn collect_distance_set(a: &[Vector3<i32>]) -> Vec<HashSet<i32>> {
+    a.iter()
+        .enumerate()
+        .map(|(i, p)| {
+            a.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, o)| (p - o).dot(&(p - o)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Squared distances between all pairs of beacons of a scanner, sorted.
+fn collect_pair_distances(a: &[Vector3<i32>]) -> Vec<i32> {
+    let mut distances: Vec<_> = a
+        .iter()
+        .enumerate()
+        .flat_map(|(i, p)| a[i + 1..].iter().map(move |o| (p - o).dot(&(p - o))))
+        .collect();
+    distances.sort_unstable();
+    distances
+}
+
+/// Whether two scanners can share 12 beacons. The 66 pairs of shared beacons
+/// have the same distances in both scanners, so scanners with fewer distances in
+/// common can be skipped without searching for an alignment.
+fn may_overlap(a: &[i32], b: &[i32]) -> bool {
+    let (mut i, mut j, mut common) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                common += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    common >= 66
+}
+
+fn try_align_recursive(
+    i: usize,
+    candidates: &[Vec<usize>],
+    current_assignment: &mut Vec<Option<usize>>,
+    taken: &mut Vec<bool>,
+    result: &mut Vec<Vec<Option<usize>>>,
+) {
+    assert_eq!(current_assignment.len(), i);
+    assert!(i <= candidates.len());
+
+    // The remaining beacons can not complete 12 pairs anymore.
+    let assigned = current_assignment.iter().filter(|i| i.is_some()).count();
+    if assigned + (candidates.len() - i) < 12 {
+        return;
+    }
+
+    if i == candidates.len() {
+        result.push(current_assignment.clone());
+        return;
+    }
+
+    for &j in &candidates[i] {
+        if taken[j] {
+            continue;
+        }
+
+        current_assignment.push(Some(j));
+        taken[j] = true;
+
+        try_align_recursive(i + 1, candidates, current_assignment, taken, result);
+
+        taken[j] = false;
+        current_assignment.pop();
+    }
+
+    current_assignment.push(None);
+    try_align_recursive(i + 1, candidates, current_assignment, taken, result);
+    current_assignment.pop();
+}
+
+/// Every way to pair at least 12 beacons of scanner `a` with beacons of scanner
+/// `b` which have at least 11 of the same distances to other beacons.
+fn try_align(
+    distances_a: &[HashSet<i32>],
+    distances_b: &[HashSet<i32>],
+) -> Vec<Vec<Option<usize>>> {
+    // The beacons of `b` every beacon of `a` may be paired with.
+    let candidates: Vec<Vec<usize>> = distances_a
+        .iter()
+        .map(|da| {
+            (0..distances_b.len())
+                .filter(|&j| da.intersection(&distances_b[j]).count() >= 11)
+                .collect()
+        })
+        .collect();
+
+    let mut result = Vec::new();
+
+    try_align_recursive(
+        0,
+        &candidates,
+        &mut Vec::new(),
+        &mut vec![false; distances_b.len()],
+        &mut result,
+    );
+
+    result
+}
+
+fn is_valid_alignment(
+    a: &[Vector3<i32>],
+    b: &[Vector3<i32>],
+    alignment: &[Option<usize>],
+) -> Option<Matrix4<i32>> {
+    let pairs = || {
+        alignment
+            .iter()
+            .enumerate()
+            .filter_map(|(i, t)| t.map(|o| (a[i], b[o])))
+    };
+
+    'transform: for transform in all_transforms() {
+        let mut pairs = pairs();
+
+        let (a, b) = pairs.next()?;
+        let transformed_b = transform * b;
+
+        let distance = transformed_b - a;
+
+        for (a, b) in pairs {
+            let transformed_b = transform * b;
+            if transformed_b - a != distance {
+                continue 'transform;
+            }
+        }
+
+        let t = transform.insert_row(3, 0);
+        let mut t = t.insert_column(3, 0);
+
+        let translation = a - transformed_b;
+
+        t[(0, 3)] = translation[0];
+        t[(1, 3)] = translation[1];
+        t[(2, 3)] = translation[2];
+        t[(3, 3)] = 1;
+
+        return Some(t);
+    }
+
+    None
+}
+
+fn all_transforms() -> impl Iterator<Item = Matrix3<i32>> {
+    let cos = |i: i32| match i.rem_euclid(4) {
+        0 => 1,
+        1 => 0,
+        2 => -1,
+        3 => 0,
+        _ => unreachable!(),
+    };
+    let sin = move |i: i32| cos(i - 3);
+
+    let x_rotations = (0..4)
+        .map(move |i| Matrix3::new(1, 0, 0, 0, cos(i), -sin(i), 0, sin(i), cos(i)));
+
+    (0..4)
+        .map(move |i| Matrix3::new(cos(i), 0, sin(i), 0, 1, 0, -sin(i), 0, cos(i)))
+        .chain(
+            [1, -1].map(|i| {
+                Matrix3::new(cos(i), -sin(i), 0, sin(i), cos(i), 0, 0, 0, 1)
+            }),
+        )
+        .flat_map(move |m| x_rotations.clone().map(move |x_rot| x_rot * m))
+}
+
+/// Aligns the scanners to scanner 0, breadth first: every step aligns the
+/// scanners which overlap with the next one which is already aligned.
+struct Alignment<'a> {
+    input: &'a [Vec<Vector3<i32>>],
+    /// Squared distances between the beacons of every scanner.
+    pair_distances: Vec<Vec<i32>>,
+    /// Squared distances from every beacon to the other beacons of its scanner.
+    distances: Vec<Vec<HashSet<i32>>>,
+    /// Transforms from the coordinates of a scanner to the ones of scanner 0.
+    transforms: Vec<Option<Matrix4<i32>>>,
+    /// Aligned scanners in the order they were aligned.
+    aligned: Vec<usize>,
+    next: usize,
+}
+
+impl<'a> Alignment<'a> {
+    fn new(input: &'a [Vec<Vector3<i32>>]) -> Self {
+        let mut transforms = vec![None; input.len()];
+        let mut aligned = Vec::new();
+
+        if !input.is_empty() {
+            transforms[0] = Some(Matrix4::identity());
+            aligned.push(0);
+        }
+
+        Self {
+            input,
+            pair_distances: input
+                .iter()
+                .map(|s| collect_pair_distances(s))
+                .collect(),
+            distances: input.iter().map(|s| collect_distance_set(s)).collect(),
+            transforms,
+            aligned,
+            next: 0,
+        }
+    }
+
+    /// Returns `false` if every scanner which can be aligned already is.
+    fn align_next(&mut self) -> bool {
+        let Some(&a_idx) = self.aligned.get(self.next) else {
+            return false;
+        };
+        let a = &self.input[a_idx];
+        let a_transform = self.transforms[a_idx].unwrap_or_else(Matrix4::identity);
+
+        for b_idx in 0..self.input.len() {
+            if self.transforms[b_idx].is_some()
+                || !may_overlap(
+                    &self.pair_distances[a_idx],
+                    &self.pair_distances[b_idx],
+                )
+            {
+                continue;
+            }
+
+            let b = &self.input[b_idx];
+            let transform =
+                try_align(&self.distances[a_idx], &self.distances[b_idx])
+                    .into_iter()
+                    .find_map(|alignment| is_valid_alignment(a, b, &alignment));
+
+            if let Some(transform) = transform {
+                self.transforms[b_idx] = Some(a_transform * transform);
+                self.aligned.push(b_idx);
+            }
+        }
+
+        self.next += 1;
+        true
+    }
+
+    fn finish(mut self) -> anyhow::Result<Vec<Matrix4<i32>>> {
+        while self.align_next() {}
+
+        self.transforms
+            .iter()
+            .enumerate()
+            .map(|(scanner, transform)| {
+                transform.ok_or(anyhow!(
+                    "Scanner {} can not be aligned to the others.",
+                    scanner
+                ))
+            })
+            .collect()
+    }
+}
+
+impl Inspector for Alignment<'_> {
+    fn show(&self, out: &mut dyn Write) -> anyhow::Result<()> {
+        writeln!(
+            out,
+            "Step {}, {} of {} scanners aligned",
+            self.next,
+            self.aligned.len(),
+            self.input.len()
+        )?;
+
+        for (scanner, transform) in self.transforms.iter().enumerate() {
+            match transform {
+                Some(t) => {
+                    let position = t * Vector4::new(0, 0, 0, 1);
+                    writeln!(
+                        out,
+                        "Scanner {}: at {},{},{}",
+                        scanner, position.x, position.y, position.z
+                    )?;
+                }
+                None => writeln!(out, "Scanner {}: not aligned", scanner)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn step(&mut self, steps: usize) -> anyhow::Result<()> {
+        for _ in 0..steps {
+            ensure!(
+                self.align_next(),
+                "Every scanner which overlaps is aligned."
+            );
+        }
+
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        *self = Alignment::new(self.input);
+    }
+
+    fn queries(&self) -> &'static [Query] {
+        &[
+            Query {
+                name: "transform",
+                args: "<scanner>",
+                help: "Transform of a scanner to the coordinates of scanner 0",
+            },
+            Query {
+                name: "beacons",
+                args: "",
+                help: "Distinct beacons seen by the aligned scanners",
+            },
+        ]
+    }
+
+    fn query(
+        &self,
+        name: &str,
+        args: &[&str],
+        out: &mut dyn Write,
+    ) -> anyhow::Result<()> {
+        match name {
+            "transform" => {
+                let scanner: usize = repl::arg(args, 0, "<scanner>")?;
+                let transform = self
+                    .transforms
+                    .get(scanner)
+                    .ok_or(anyhow!("There is no scanner {}.", scanner))?
+                    .ok_or(anyhow!("Scanner {} is not aligned yet.", scanner))?;
+                write!(out, "{}", transform)?;
+            }
+            "beacons" => {
+                let beacons: HashSet<_> = self
+                    .aligned
+                    .iter()
+                    .flat_map(|&scanner| {
+                        let transform = self.transforms[scanner];
+                        self.input[scanner].iter().filter_map(move |p| {
+                            Some(transform? * p.insert_row(3, 1))
+                        })
+                    })
+                    .collect();
+                writeln!(out, "{}", beacons.len())?;
+            }
+            _ => bail!("There is no query '{}'.", name),
+        }
+
+        Ok(())
+    }
+}
+
+fn calc_all_scanner_transforms(
+    input: &[Vec<Vector3<i32>>],
+) -> anyhow::Result<Vec<Matrix4<i32>>> {
+    Alignment::new(input).finish()
+}
+
+fn part1(input: &[Vec<Vector3<i32>>]) -> anyhow::Result<usize> {
+    let transforms = calc_all_scanner_transforms(input)?;
+
+    let mut points = HashSet::new();
+
+    for (idx, scanner) in input.iter().enumerate() {
+        for p in scanner {
+            points.insert(transforms[idx] * p.insert_row(3, 1));
+        }
+    }
+
+    Ok(points.len())
+}
+
+fn part2(input: &[Vec<Vector3<i32>>]) -> anyhow::Result<i32> {
+    let transforms = calc_all_scanner_transforms(input)?;
+
+    let scanner_pos: Vec<_> = transforms
+        .iter()
+        .map(|t| t * Vector4::<i32>::new(0, 0, 0, 1))
+        .map(|p| Point3::new(p.x, p.y, p.z))
+        .collect();
+
+    let max = iproduct!(&scanner_pos, &scanner_pos)
+        .map(|(a, b)| a.manhattan(*b))
+        .max()
+        .ok_or(anyhow!("There are no scanners."))?;
+
+    Ok(max)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: Day = Day(19);
+
+    type Input = Vec<Vec<Vector3<i32>>>;
+    type First = usize;
+    type Second = i32;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<i32> {
+        part2(input)
+    }
+
+    fn inspector(input: &Self::Input) -> anyhow::Result<Box<dyn Inspector + '_>> {
+        Ok(Box::new(Alignment::new(input)))
+    }
+}
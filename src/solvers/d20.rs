@@ -0,0 +1,235 @@
+use anyhow::ensure;
+
+use crate::{
+    geom::Point2,
+    grid::Grid,
+    parse::ParseError,
+    simulation::{self, Simulation},
+    visualize::{Canvas, Cell, Frame},
+    Day, Param, Params, Puzzle, Solution,
+};
+
+/// Every combination of the nine pixels around a pixel.
+const SEQUENCE_LENGTH: usize = 512;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pixel {
+    Dark,
+    Light,
+}
+
+#[derive(Clone)]
+pub struct InfiniteGrid {
+    inner: Grid<Pixel>,
+    surrounding: Pixel,
+}
+
+pub struct ParseResult {
+    sequence: Vec<Pixel>,
+    input: InfiniteGrid,
+}
+
+fn parse_pixel(c: char) -> Option<Pixel> {
+    match c {
+        '.' => Some(Pixel::Dark),
+        '#' => Some(Pixel::Light),
+        _ => None,
+    }
+}
+
+fn parse(input: &str) -> anyhow::Result<ParseResult> {
+    let mut parts = input.trim().split("\n\n");
+
+    let sequence_text = parts
+        .next()
+        .ok_or_else(|| ParseError::end(input, "Missing enhancement sequence"))?
+        .trim();
+    let sequence: Vec<_> = sequence_text
+        .char_indices()
+        .map(|(i, c)| {
+            parse_pixel(c).ok_or_else(|| {
+                ParseError::new(
+                    input,
+                    &sequence_text[i..i + c.len_utf8()],
+                    format!("Illegal pixel '{}'", c),
+                )
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    ensure!(
+        sequence.len() == SEQUENCE_LENGTH,
+        ParseError::new(
+            input,
+            sequence_text,
+            format!(
+                "The enhancement sequence has {} pixels instead of {}",
+                sequence.len(),
+                SEQUENCE_LENGTH
+            )
+        )
+    );
+
+    let image = parts
+        .next()
+        .ok_or_else(|| ParseError::end(input, "Missing image"))?;
+    let image = Grid::parse(input, image, parse_pixel)?;
+
+    Ok(ParseResult {
+        sequence,
+        input: InfiniteGrid {
+            inner: image,
+            surrounding: Pixel::Dark,
+        },
+    })
+}
+
+fn enhance(image: &InfiniteGrid, sequence: &[Pixel]) -> InfiniteGrid {
+    let new_width = image.inner.get_width() + 2;
+    let new_height = image.inner.get_height() + 2;
+
+    let mut new_image = Grid::new(new_width, new_height, Pixel::Dark);
+
+    for (x, y, p) in new_image.iter_coords_mut::<i32>() {
+        let mut sequence_idx = 0;
+
+        // The new image has a border of one pixel around the old one.
+        let old = Point2::new(x - 1, y - 1);
+
+        for p in old
+            .window()
+            .map(|p| *image.inner.get_point(p).unwrap_or(&image.surrounding))
+        {
+            sequence_idx <<= 1;
+            if p == Pixel::Light {
+                sequence_idx |= 1;
+            }
+        }
+
+        *p = sequence[sequence_idx];
+    }
+
+    // The length of the sequence is checked by `parse`.
+    let new_surrounding = if image.surrounding == Pixel::Light {
+        sequence[SEQUENCE_LENGTH - 1]
+    } else {
+        sequence[0]
+    };
+
+    InfiniteGrid {
+        inner: new_image,
+        surrounding: new_surrounding,
+    }
+}
+
+fn image_frame(grid: &Grid<Pixel>) -> Frame {
+    Frame::from_grid(grid, |_, _, &p| {
+        Cell::plain(if p == Pixel::Light { '#' } else { '.' })
+    })
+}
+
+/// The image enhanced once per step.
+struct Enhancement<'a> {
+    sequence: &'a [Pixel],
+    image: InfiniteGrid,
+    remaining: usize,
+}
+
+impl<'a> Enhancement<'a> {
+    fn new(input: &'a ParseResult, iterations: usize) -> Self {
+        Self {
+            sequence: &input.sequence,
+            image: input.input.clone(),
+            remaining: iterations,
+        }
+    }
+}
+
+impl Simulation for Enhancement<'_> {
+    fn step(&mut self) -> bool {
+        if self.remaining == 0 {
+            return false;
+        }
+
+        self.image = enhance(&self.image, self.sequence);
+        self.remaining -= 1;
+        true
+    }
+
+    fn frame(&self) -> Frame {
+        image_frame(&self.image.inner)
+    }
+}
+
+fn solve(input: &ParseResult, iterations: usize) -> anyhow::Result<usize> {
+    let ParseResult { sequence, input } = input;
+    let mut input = input.clone();
+
+    for _ in 0..iterations {
+        input = enhance(&input, sequence);
+    }
+
+    ensure!(
+        input.surrounding == Pixel::Dark,
+        "Infinitely many pixels are lit after {} iterations.",
+        iterations
+    );
+
+    let result = input.inner.iter().filter(|&&p| p == Pixel::Light).count();
+
+    Ok(result)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: Day = Day(20);
+
+    type Input = ParseResult;
+    type First = usize;
+    type Second = usize;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        description: "Times the image is enhanced",
+        first: Some("2"),
+        second: Some("50"),
+    }];
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<usize> {
+        Self::part1_with(input, &Params::defaults(Self::PARAMS, Puzzle::First))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<usize> {
+        Self::part2_with(input, &Params::defaults(Self::PARAMS, Puzzle::Second))
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> anyhow::Result<usize> {
+        solve(input, params.get("steps")?)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> anyhow::Result<usize> {
+        solve(input, params.get("steps")?)
+    }
+
+    fn visualize(
+        input: &Self::Input,
+        puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        let steps = Params::defaults(Self::PARAMS, puzzle).get("steps")?;
+        simulation::record(&mut Enhancement::new(input, steps), canvas)
+    }
+
+    fn simulation(
+        input: &Self::Input,
+        puzzle: Puzzle,
+    ) -> anyhow::Result<Box<dyn Simulation + '_>> {
+        let steps = Params::defaults(Self::PARAMS, puzzle).get("steps")?;
+        Ok(Box::new(Enhancement::new(input, steps)))
+    }
+}
@@ -0,0 +1,269 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use anyhow::{anyhow, bail, ensure};
+use regex::Regex;
+
+use crate::{
+    parse::{self, ParseError},
+    Day, Param, Params, Puzzle, Solution,
+};
+
+fn parse(input: &str) -> anyhow::Result<[u64; 2]> {
+    static INPUT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"Player (?P<player>\d+) starting position: (?P<start>\d+)")
+            .unwrap()
+    });
+
+    let mut result = [None; 2];
+
+    for line in input.trim().lines() {
+        let line = line.trim();
+        let cap = INPUT_REGEX.captures(line).ok_or_else(|| {
+            ParseError::new(input, line, format!("Invalid input '{}'", line))
+        })?;
+
+        let player: usize = parse::number(input, &cap["player"])?;
+        let start: u64 = parse::number(input, &cap["start"])?;
+
+        ensure!(
+            (1..=10).contains(&start),
+            ParseError::new(
+                input,
+                &cap["start"],
+                format!("Starting position {} is not on the board", start)
+            )
+        );
+
+        let position = player
+            .checked_sub(1)
+            .and_then(|p| result.get_mut(p))
+            .ok_or_else(|| {
+                ParseError::new(
+                    input,
+                    &cap["player"],
+                    format!("Invalid player {}", player),
+                )
+            })?;
+        *position = Some(start);
+    }
+
+    match result {
+        [Some(first), Some(second)] => Ok([first, second]),
+        _ => bail!(ParseError::end(
+            input,
+            "Missing starting position of a player"
+        )),
+    }
+}
+
+struct DeterministicDice(u64);
+
+impl DeterministicDice {
+    fn new() -> Self {
+        Self(1)
+    }
+
+    fn next(&mut self) -> u64 {
+        let result = self.0;
+        self.0 = mod1(self.0 + 1, 100);
+        result
+    }
+}
+
+fn mod1(a: u64, m: u64) -> u64 {
+    (a - 1) % m + 1
+}
+
+/// Checks the parameters of the game, the starting positions are only checked
+/// against the usual board by `parse`.
+fn check_game(positions: &[u64; 2], goal: u64, board: u64) -> anyhow::Result<()> {
+    ensure!(goal > 0, "The goal has to be at least one point.");
+    ensure!(board > 0, "The board needs at least one space.");
+    ensure!(
+        board <= u64::from(u32::MAX),
+        "The board has more than {} spaces.",
+        u32::MAX
+    );
+
+    for &position in positions {
+        ensure!(
+            position <= board,
+            "Starting position {} is not on the board of {} spaces.",
+            position,
+            board
+        );
+    }
+
+    Ok(())
+}
+
+fn part1(positions: &[u64; 2], goal: u64, board: u64) -> anyhow::Result<u64> {
+    check_game(positions, goal, board)?;
+
+    let mut positions = *positions;
+
+    let mut scores = [0u64; 2];
+
+    let mut dice = DeterministicDice::new();
+
+    let mut total_dice_throws: u64 = 0;
+
+    for dice_throw in 0u64.. {
+        let player = (dice_throw % 2) as usize;
+
+        let dice_result = dice.next() + dice.next() + dice.next();
+
+        positions[player] = mod1(positions[player] + dice_result, board);
+        scores[player] = scores[player]
+            .checked_add(positions[player])
+            .ok_or(anyhow!("The score does not fit into 64 bits."))?;
+
+        if scores[player] >= goal {
+            total_dice_throws = 3 * (dice_throw + 1);
+            break;
+        }
+    }
+
+    total_dice_throws
+        .checked_mul(scores[0].min(scores[1]))
+        .ok_or(anyhow!("The result does not fit into 64 bits."))
+}
+
+/// Sums of three rolls of the Dirac dice with the number of universes each.
+const DIRAC_ROLLS: [(u64, u64); 7] =
+    [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+struct FinishResult {
+    /// Universes in which the player reaches the goal in each turn.
+    finishes: Vec<u64>,
+    /// Universes in which the player has not reached the goal before each turn.
+    timelines: Vec<u64>,
+}
+
+impl FinishResult {
+    /// Universes in which the player has not reached the goal before `turn`,
+    /// which are none after the last one.
+    fn timelines(&self, turn: usize) -> u64 {
+        self.timelines.get(turn).copied().unwrap_or(0)
+    }
+}
+
+/// Plays a single player until it reaches the goal in every universe.
+fn collect_finishes(
+    pos: u64,
+    goal: u64,
+    board: u64,
+) -> anyhow::Result<FinishResult> {
+    let overflow = || anyhow!("The universes do not fit into 64 bits.");
+
+    let mut finishes = Vec::new();
+    let mut timelines = Vec::new();
+
+    // Universes by position and score of the player, which has not won yet.
+    let mut universes = HashMap::from([((pos, 0), 1u64)]);
+
+    while !universes.is_empty() {
+        let mut finished: u64 = 0;
+        let mut next = HashMap::new();
+
+        for (&(pos, score), &count) in &universes {
+            for (dice, multiplier) in DIRAC_ROLLS {
+                let new_pos = mod1(pos + dice, board);
+                let new_score = u64::saturating_add(score, new_pos);
+                let new_count =
+                    count.checked_mul(multiplier).ok_or_else(overflow)?;
+
+                let target = if new_score >= goal {
+                    &mut finished
+                } else {
+                    next.entry((new_pos, new_score)).or_insert(0)
+                };
+                *target = target.checked_add(new_count).ok_or_else(overflow)?;
+            }
+        }
+
+        let total = universes
+            .values()
+            .try_fold(0u64, |total, &count| total.checked_add(count))
+            .ok_or_else(overflow)?;
+
+        timelines.push(total);
+        finishes.push(finished);
+        universes = next;
+    }
+
+    Ok(FinishResult {
+        finishes,
+        timelines,
+    })
+}
+
+fn part2(positions: &[u64; 2], goal: u64, board: u64) -> anyhow::Result<u64> {
+    check_game(positions, goal, board)?;
+
+    let overflow = || anyhow!("The universes do not fit into 64 bits.");
+
+    let first = collect_finishes(positions[0], goal, board)?;
+    let second = collect_finishes(positions[1], goal, board)?;
+
+    // The first player wins in a turn if the second one has not won before, the
+    // second player wins if the first one does not win in the next turn either.
+    let mut player1_wins: u64 = 0;
+    for (i, &w) in first.finishes.iter().enumerate() {
+        let wins = w.checked_mul(second.timelines(i)).ok_or_else(overflow)?;
+        player1_wins = player1_wins.checked_add(wins).ok_or_else(overflow)?;
+    }
+
+    let mut player2_wins: u64 = 0;
+    for (i, &w) in second.finishes.iter().enumerate() {
+        let wins = w.checked_mul(first.timelines(i + 1)).ok_or_else(overflow)?;
+        player2_wins = player2_wins.checked_add(wins).ok_or_else(overflow)?;
+    }
+
+    Ok(u64::max(player1_wins, player2_wins))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: Day = Day(21);
+
+    type Input = [u64; 2];
+    type First = u64;
+    type Second = u64;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "goal",
+            description: "Score with which a player wins",
+            first: Some("1000"),
+            second: Some("21"),
+        },
+        Param {
+            name: "board",
+            description: "Spaces on the circular board",
+            first: Some("10"),
+            second: Some("10"),
+        },
+    ];
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u64> {
+        Self::part1_with(input, &Params::defaults(Self::PARAMS, Puzzle::First))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u64> {
+        Self::part2_with(input, &Params::defaults(Self::PARAMS, Puzzle::Second))
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> anyhow::Result<u64> {
+        part1(input, params.get("goal")?, params.get("board")?)
+    }
+
+    fn part2_with(input: &Self::Input, params: &Params) -> anyhow::Result<u64> {
+        part2(input, params.get("goal")?, params.get("board")?)
+    }
+}
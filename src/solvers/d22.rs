@@ -0,0 +1,172 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use anyhow::{anyhow, ensure};
+use regex::Regex;
+use tracing::{debug, trace};
+
+use crate::{
+    geom::{AxisRange, BoundingBox, Point},
+    parse::{self, ParseError},
+    Day, Param, Params, Puzzle, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    On,
+    Off,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cuboid {
+    state: State,
+    bounds: BoundingBox<i64, 3>,
+}
+
+impl Cuboid {
+    /// Whether the cuboid lies within `-region..=region` on every axis.
+    fn is_within(&self, region: i64) -> bool {
+        BoundingBox::new(Point([-region; 3]), Point([region; 3]))
+            .contains_box(&self.bounds)
+    }
+}
+
+fn parse(input: &str) -> anyhow::Result<Vec<Cuboid>> {
+    static INPUT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"(?P<state>on|off) x=(?P<x_start>-?\d+)..(?P<x_end>-?\d+),y=(?P<y_start>-?\d+)..(?P<y_end>-?\d+),z=(?P<z_start>-?\d+)..(?P<z_end>-?\d+)").unwrap()
+    });
+
+    input
+        .trim()
+        .lines()
+        .map(|line| -> anyhow::Result<Cuboid> {
+            let caps = INPUT_REGEX.captures(line.trim()).ok_or_else(|| {
+                ParseError::new(input, line.trim(), "Illegal input")
+            })?;
+
+            let state = match &caps["state"] {
+                "on" => State::On,
+                _ => State::Off,
+            };
+
+            let range = |axis: &str| -> anyhow::Result<AxisRange<i64>> {
+                let start = &caps[format!("{}_start", axis).as_str()];
+                let end = &caps[format!("{}_end", axis).as_str()];
+
+                let range = AxisRange::new(
+                    parse::number::<i32>(input, start)?.into(),
+                    parse::number::<i32>(input, end)?.into(),
+                );
+
+                ensure!(
+                    range.start <= range.end,
+                    ParseError::new(
+                        input,
+                        parse::spanning(input, start, end),
+                        format!("Empty {} range", axis)
+                    )
+                );
+
+                Ok(range)
+            };
+
+            let bounds =
+                BoundingBox::from_ranges([range("x")?, range("y")?, range("z")?]);
+
+            Ok(Cuboid { state, bounds })
+        })
+        .collect()
+}
+
+fn part1(input: &[Cuboid], region: i64) -> anyhow::Result<usize> {
+    ensure!(region >= 0, "The region can not have a negative size.");
+
+    let input: Vec<_> = input
+        .iter()
+        .filter(|c| c.is_within(region))
+        .cloned()
+        .collect();
+
+    let enabled = part2(&input)?;
+
+    usize::try_from(enabled)
+        .map_err(|_| anyhow!("There are more than {} cubes on.", usize::MAX))
+}
+
+/// Number of cubes in `bounds`.
+fn volume(bounds: &BoundingBox<i64, 3>) -> i128 {
+    bounds
+        .ranges()
+        .iter()
+        .map(|range| i128::from(range.end - range.start + 1))
+        .product()
+}
+
+fn part2(input: &[Cuboid]) -> anyhow::Result<i128> {
+    // Every box is counted a number of times. Overlaps with earlier boxes are
+    // counted again with the opposite count, so every cube is counted once if it
+    // is on and not at all if it is off. Counts of equal boxes are summed, which
+    // keeps the boxes of overlapping cuboids from multiplying.
+    let mut counted: HashMap<BoundingBox<i64, 3>, i128> = HashMap::new();
+
+    for (i, cuboid) in input.iter().enumerate() {
+        trace!(cuboid = i, counted = counted.len(), "processing cuboid");
+
+        let mut changes: HashMap<_, i128> = HashMap::new();
+        for (bounds, count) in &counted {
+            if let Some(overlap) = bounds.intersection(&cuboid.bounds) {
+                *changes.entry(overlap).or_default() -= count;
+            }
+        }
+        if cuboid.state == State::On {
+            *changes.entry(cuboid.bounds).or_default() += 1;
+        }
+
+        for (bounds, change) in changes {
+            let count = counted.entry(bounds).or_default();
+            *count += change;
+            if *count == 0 {
+                counted.remove(&bounds);
+            }
+        }
+    }
+
+    debug!(counted = counted.len(), "processed the cuboids");
+
+    Ok(counted
+        .iter()
+        .map(|(bounds, count)| count * volume(bounds))
+        .sum())
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: Day = Day(22);
+
+    type Input = Vec<Cuboid>;
+    type First = usize;
+    type Second = i128;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "region",
+        description: "Cubes from -region to region on every axis are initialized",
+        first: Some("50"),
+        second: None,
+    }];
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<usize> {
+        Self::part1_with(input, &Params::defaults(Self::PARAMS, Puzzle::First))
+    }
+
+    fn part1_with(input: &Self::Input, params: &Params) -> anyhow::Result<usize> {
+        part1(input, params.get("region")?)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<i128> {
+        part2(input)
+    }
+}
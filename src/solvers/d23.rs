@@ -1,12 +1,15 @@
 use std::collections::{BinaryHeap, HashMap};
 
-use anyhow::bail;
+use anyhow::{bail, ensure};
 use itertools::{Either, Itertools};
+use strum::IntoEnumIterator;
 
-use crate::grid::Grid;
+use crate::{grid::Grid, parse::ParseError, Day, Solution};
+
+const BUCKET_COUNT: usize = 4;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::EnumIter)]
-enum AmphiType {
+pub enum AmphiType {
     Amber,
     Bronze,
     Copper,
@@ -34,28 +37,37 @@ impl AmphiType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Tile {
+pub enum Tile {
     Wall,
     Empty,
     Amphi(AmphiType),
 }
 
-fn parse(grid: &str) -> anyhow::Result<Grid<Tile>> {
-    let height = grid.trim().lines().count();
-    let width = grid.trim().lines().next().unwrap().trim().chars().count();
+fn parse(text: &str) -> anyhow::Result<Grid<Tile>> {
+    let height = text.trim().lines().count();
+    let width = text
+        .trim()
+        .lines()
+        .map(|l| l.chars().count())
+        .max()
+        .ok_or_else(|| ParseError::end(text, "The burrow is empty"))?;
 
-    let grid: Result<Vec<Tile>, _> = grid
+    let grid: Result<Vec<Tile>, _> = text
         .trim()
         .lines()
-        .flat_map(|l| l.chars().pad_using(width, |_| '#'))
-        .map(|c| match c {
+        .flat_map(|l| {
+            l.char_indices()
+                .map(move |(i, c)| (&l[i..i + c.len_utf8()], c))
+                .pad_using(width, |_| ("", '#'))
+        })
+        .map(|(part, c)| match c {
             '.' => Ok(Tile::Empty),
             ' ' | '#' => Ok(Tile::Wall),
             'A' => Ok(Tile::Amphi(AmphiType::Amber)),
             'B' => Ok(Tile::Amphi(AmphiType::Bronze)),
             'C' => Ok(Tile::Amphi(AmphiType::Copper)),
             'D' => Ok(Tile::Amphi(AmphiType::Desert)),
-            _ => bail!("Illegal tile '{}'.", c),
+            _ => bail!(ParseError::new(text, part, format!("Illegal tile '{}'", c))),
         })
         .collect();
 
@@ -71,7 +83,7 @@ struct State {
     max_bucket_size: usize,
 }
 
-fn build_initial_state(grid: &Grid<Tile>) -> State {
+fn build_initial_state(grid: &Grid<Tile>) -> anyhow::Result<State> {
     let mut amphis: Vec<_> = grid
         .iter_coords::<i32>()
         .filter_map(|a| match a.2 {
@@ -87,13 +99,41 @@ fn build_initial_state(grid: &Grid<Tile>) -> State {
         buckets.push(iter.map(|(_, _, amphi)| *amphi).collect::<Vec<_>>());
     }
 
+    ensure!(
+        buckets.len() == BUCKET_COUNT,
+        "Expected {} side rooms, found {}.",
+        BUCKET_COUNT,
+        buckets.len()
+    );
+
+    let max_bucket_size = buckets[0].len();
+    ensure!(
+        buckets.iter().all(|b| b.len() == max_bucket_size),
+        "All side rooms must have the same size."
+    );
+
+    for amphi_type in AmphiType::iter() {
+        let count = buckets
+            .iter()
+            .flatten()
+            .filter(|&&a| a == amphi_type)
+            .count();
+        ensure!(
+            count == max_bucket_size,
+            "Expected {} amphipods of type {:?}, found {}.",
+            max_bucket_size,
+            amphi_type,
+            count
+        );
+    }
+
     let hallway_len = buckets.len() * 2 + 3;
 
-    State {
+    Ok(State {
         hallway: vec![None; hallway_len],
-        max_bucket_size: buckets[0].len(),
+        max_bucket_size,
         buckets,
-    }
+    })
 }
 
 fn bucket_to_hallway_pos(bucket_idx: usize) -> usize {
@@ -148,7 +188,8 @@ fn adjacent_states(state: &State) -> Vec<(u32, State)> {
                 let vertical_len = (state.max_bucket_size
                     - state.buckets[bucket_idx].len()
                     + 1) as u32;
-                let horizontal_len = (hallway_pos as i64 - pos as i64).abs() as u32;
+                let horizontal_len =
+                    (hallway_pos as i64 - pos as i64).unsigned_abs() as u32;
                 let cost = (vertical_len + horizontal_len) * top_amphi.move_cost();
 
                 let mut new_state = state.clone();
@@ -185,7 +226,7 @@ fn adjacent_states(state: &State) -> Vec<(u32, State)> {
             continue;
         }
 
-        let horizontal_len = (pos as i64 - bucket_pos as i64).abs() as u32;
+        let horizontal_len = (pos as i64 - bucket_pos as i64).unsigned_abs() as u32;
         let vertical_len =
             (state.max_bucket_size - state.buckets[amphi_bucket_idx].len()) as u32;
         let cost = (horizontal_len + vertical_len) * amphi.move_cost();
@@ -199,7 +240,7 @@ fn adjacent_states(state: &State) -> Vec<(u32, State)> {
     result
 }
 
-#[derive(Ord, Eq)]
+#[derive(Eq)]
 struct QueueEntry {
     id: u64,
     cost: u32,
@@ -213,7 +254,13 @@ impl PartialEq for QueueEntry {
 
 impl PartialOrd for QueueEntry {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.cost.partial_cmp(&other.cost).map(|a| a.reverse())
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cost.cmp(&other.cost).reverse()
     }
 }
 
@@ -259,7 +306,7 @@ impl Solver {
         id
     }
 
-    fn search(&mut self) -> u32 {
+    fn search(&mut self) -> anyhow::Result<u32> {
         while let Some(QueueEntry { id, cost }) = self.queue.pop() {
             if self
                 .visited
@@ -270,7 +317,10 @@ impl Solver {
                 continue;
             }
 
-            let state = self.id_to_state.get(&id).unwrap();
+            let state = &self.id_to_state[&id];
+            if state == &self.target_state {
+                return Ok(cost);
+            }
             //eprintln!("----------------------------------------------");
             //dbg!(id);
             //dbg!(cost);
@@ -282,10 +332,6 @@ impl Solver {
                 //dbg!(new_state_cost);
                 //dbg!(&next_state);
 
-                if &next_state == &self.target_state {
-                    return new_state_cost;
-                }
-
                 let next_state_id = self.add_state(next_state);
                 //dbg!(next_state_id);
 
@@ -304,7 +350,7 @@ impl Solver {
             }
         }
 
-        unreachable!("Target state could not be found")
+        bail!("The amphipods can not be organized.")
     }
 }
 
@@ -320,23 +366,33 @@ fn create_target_state(initial_state: &State) -> State {
     target_state
 }
 
-fn solve(input: &str) -> anyhow::Result<u32> {
-    let grid = parse(input)?;
-
-    let initial_state = build_initial_state(&grid);
+fn solve(grid: &Grid<Tile>) -> anyhow::Result<u32> {
+    let initial_state = build_initial_state(grid)?;
     let target_state = create_target_state(&initial_state);
 
     let mut solver = Solver::new(initial_state, target_state);
 
-    let result = solver.search();
-
-    Ok(result)
+    solver.search()
 }
 
-pub fn part1(input: &str) -> anyhow::Result<u32> {
-    solve(input)
-}
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: Day = Day(23);
 
-pub fn part2(input: &str) -> anyhow::Result<u32> {
-    solve(input)
+    type Input = Grid<Tile>;
+    type First = u32;
+    type Second = u32;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u32> {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u32> {
+        solve(input)
+    }
 }
@@ -0,0 +1,700 @@
+#![allow(unused)]
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Write},
+    rc::Rc,
+};
+
+use anyhow::{anyhow, bail, ensure};
+use tracing::{debug, Level};
+
+use crate::{
+    parse::ParseError,
+    visualize::{Canvas, Frame},
+    Day, Puzzle, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    X,
+    Y,
+    Z,
+    W,
+    Number(i64),
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Operand),
+    Add(Operand, Operand),
+    Mul(Operand, Operand),
+    Mod(Operand, Operand),
+    Div(Operand, Operand),
+    Eql(Operand, Operand),
+}
+
+fn parse_operand(text: &str, input: &str) -> anyhow::Result<Operand> {
+    let operand = input.parse::<i64>();
+
+    if let Ok(operand) = operand {
+        return Ok(Operand::Number(operand));
+    }
+
+    let op = match input.chars().next() {
+        Some('w') => Operand::W,
+        Some('x') => Operand::X,
+        Some('y') => Operand::Y,
+        Some('z') => Operand::Z,
+        _ => bail!(ParseError::new(
+            text,
+            input,
+            format!("Invalid operand '{}'", input)
+        )),
+    };
+
+    Ok(op)
+}
+
+fn parse_operand_with_two_args<'a>(
+    text: &str,
+    line: &str,
+    op: fn(Operand, Operand) -> Instruction,
+    mut remaining: impl Iterator<Item = &'a str>,
+) -> anyhow::Result<Instruction> {
+    let error = |message| ParseError::new(text, line, message);
+
+    let lhs = remaining
+        .next()
+        .ok_or_else(|| error("Missing lhs operand"))?;
+    let rhs = remaining
+        .next()
+        .ok_or_else(|| error("Missing rhs operand"))?;
+
+    let lhs_operand = parse_operand(text, lhs)?;
+    ensure!(
+        !matches!(lhs_operand, Operand::Number(_)),
+        ParseError::new(
+            text,
+            lhs,
+            "Left hand side of binary operation has to be variable"
+        )
+    );
+    let lhs = lhs_operand;
+    let rhs = parse_operand(text, rhs)?;
+
+    Ok(op(lhs, rhs))
+}
+
+fn parse(input: &str) -> anyhow::Result<Vec<Instruction>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let error = |message| ParseError::new(input, line, message);
+            let operand =
+                parts.next().ok_or_else(|| error("Missing instruction"))?;
+
+            match operand {
+                "inp" => {
+                    let op_text = parts
+                        .next()
+                        .ok_or_else(|| error("Missing input operand"))?;
+                    let op = parse_operand(input, op_text)?;
+                    ensure!(
+                        !matches!(op, Operand::Number(_)),
+                        ParseError::new(
+                            input,
+                            op_text,
+                            "Operand of input has to be variable"
+                        )
+                    );
+                    Ok(Instruction::Inp(op))
+                }
+                "add" => {
+                    parse_operand_with_two_args(input, line, Instruction::Add, parts)
+                }
+                "mul" => {
+                    parse_operand_with_two_args(input, line, Instruction::Mul, parts)
+                }
+                "div" => {
+                    parse_operand_with_two_args(input, line, Instruction::Div, parts)
+                }
+                "mod" => {
+                    parse_operand_with_two_args(input, line, Instruction::Mod, parts)
+                }
+                "eql" => {
+                    parse_operand_with_two_args(input, line, Instruction::Eql, parts)
+                }
+                _ => bail!(ParseError::new(
+                    input,
+                    operand,
+                    format!("Invalid instruction '{}'", operand)
+                )),
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NodeOp {
+    Literal(i64),
+    Add(Rc<Node>, Rc<Node>),
+    Mul(Rc<Node>, Rc<Node>),
+    Div(Rc<Node>, Rc<Node>),
+    Mod(Rc<Node>, Rc<Node>),
+    Eql(Rc<Node>, Rc<Node>),
+    Input,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    id: u32,
+    operation: NodeOp,
+}
+
+struct Constructor {
+    next_id: u32,
+}
+
+impl Constructor {
+    fn new_add(&mut self, lhs: Rc<Node>, rhs: Rc<Node>) -> Node {
+        Node {
+            id: self.next_id(),
+            operation: NodeOp::Add(lhs, rhs),
+        }
+    }
+
+    fn new_mul(&mut self, lhs: Rc<Node>, rhs: Rc<Node>) -> Node {
+        Node {
+            id: self.next_id(),
+            operation: NodeOp::Mul(lhs, rhs),
+        }
+    }
+
+    fn new_div(&mut self, lhs: Rc<Node>, rhs: Rc<Node>) -> Node {
+        Node {
+            id: self.next_id(),
+            operation: NodeOp::Div(lhs, rhs),
+        }
+    }
+
+    fn new_mod(&mut self, lhs: Rc<Node>, rhs: Rc<Node>) -> Node {
+        Node {
+            id: self.next_id(),
+            operation: NodeOp::Mod(lhs, rhs),
+        }
+    }
+
+    fn new_eql(&mut self, lhs: Rc<Node>, rhs: Rc<Node>) -> Node {
+        Node {
+            id: self.next_id(),
+            operation: NodeOp::Eql(lhs, rhs),
+        }
+    }
+    fn new_input(&mut self, _op: Operand) -> Node {
+        Node {
+            id: self.next_id(),
+            operation: NodeOp::Input,
+        }
+    }
+
+    fn new_literal(&mut self, val: i64) -> Node {
+        Node {
+            id: self.next_id(),
+            operation: NodeOp::Literal(val),
+        }
+    }
+
+    fn next_id(&mut self) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+}
+
+fn build_graph_from_instructions(instructions: &[Instruction]) -> Node {
+    let var_to_idx = |v: Operand| match v {
+        Operand::X => 0,
+        Operand::Y => 1,
+        Operand::Z => 2,
+        Operand::W => 3,
+        Operand::Number(_) => {
+            unreachable!("Assigned operands are validated while parsing.")
+        }
+    };
+
+    let mut c = Constructor { next_id: 1 };
+
+    let operand_to_graph =
+        |op: Operand, vars: &[Node; 4], c: &mut Constructor| match op {
+            Operand::X | Operand::Y | Operand::Z | Operand::W => {
+                vars[var_to_idx(op)].clone()
+            }
+            Operand::Number(val) => c.new_literal(val),
+        };
+    let binary_instr_to_graph =
+        |bin: fn(&mut Constructor, _, _) -> Node,
+         lhs: Operand,
+         rhs: Operand,
+         vars: &mut [Node; 4],
+         c: &mut Constructor| {
+            let left = operand_to_graph(lhs, vars, c);
+            let right = operand_to_graph(rhs, vars, c);
+            vars[var_to_idx(lhs)] = bin(c, Rc::new(left), Rc::new(right))
+        };
+
+    let mut vars = [
+        c.new_literal(0),
+        c.new_literal(0),
+        c.new_literal(0),
+        c.new_literal(0),
+    ];
+
+    #[rustfmt::skip]
+    #[allow(clippy::let_unit_value)]
+    let _ = for instr in instructions {
+        match instr {
+            Instruction::Inp(op) => vars[var_to_idx(*op)] = c.new_input(*op),
+            Instruction::Add(lhs, rhs) => {
+                binary_instr_to_graph(|c, l, r| c.new_add(l, r), *lhs, *rhs, &mut vars, &mut c);
+            }
+            Instruction::Mul(lhs, rhs) => {
+                binary_instr_to_graph(|c, l, r| c.new_mul(l, r), *lhs, *rhs, &mut vars, &mut c);
+            }
+            Instruction::Div(lhs, rhs) => {
+                binary_instr_to_graph(|c, l, r| c.new_div(l, r), *lhs, *rhs, &mut vars, &mut c);
+            }
+            Instruction::Mod(lhs, rhs) => {
+                binary_instr_to_graph(|c, l, r| c.new_mod(l, r), *lhs, *rhs, &mut vars, &mut c);
+            }
+            Instruction::Eql(lhs, rhs) => {
+                binary_instr_to_graph(|c, l, r| c.new_eql(l, r), *lhs, *rhs, &mut vars, &mut c);
+            }
+        };
+    };
+
+    vars[var_to_idx(Operand::Z)].clone()
+}
+
+/// The computation of `z` in the dot language of graphviz.
+fn dump_graph(graph: &Node) -> Result<String, fmt::Error> {
+    fn dump_recursive(
+        graph: &Node,
+        visited: &mut HashSet<u32>,
+        out: &mut String,
+    ) -> fmt::Result {
+        if visited.contains(&graph.id) {
+            return Ok(());
+        } else {
+            visited.insert(graph.id);
+        }
+        match &graph.operation {
+            NodeOp::Literal(l) => {
+                writeln!(out, "{}[label={}]", graph.id, l)?;
+            }
+            NodeOp::Add(lhs, rhs) => {
+                writeln!(out, "{}[label=\"add\"]", graph.id)?;
+                writeln!(out, "{} -> {} [label=lhs]", graph.id, lhs.id)?;
+                writeln!(out, "{} -> {} [label=rhs]", graph.id, rhs.id)?;
+                dump_recursive(lhs, visited, out)?;
+                dump_recursive(rhs, visited, out)?;
+            }
+            NodeOp::Mul(lhs, rhs) => {
+                writeln!(out, "{}[label=\"mul\"]", graph.id)?;
+                writeln!(out, "{} -> {} [label=lhs]", graph.id, lhs.id)?;
+                writeln!(out, "{} -> {} [label=rhs]", graph.id, rhs.id)?;
+                dump_recursive(lhs, visited, out)?;
+                dump_recursive(rhs, visited, out)?;
+            }
+            NodeOp::Div(lhs, rhs) => {
+                writeln!(out, "{}[label=\"div\"]", graph.id)?;
+                writeln!(out, "{} -> {} [label=lhs]", graph.id, lhs.id)?;
+                writeln!(out, "{} -> {} [label=rhs]", graph.id, rhs.id)?;
+                dump_recursive(lhs, visited, out)?;
+                dump_recursive(rhs, visited, out)?;
+            }
+            NodeOp::Mod(lhs, rhs) => {
+                writeln!(out, "{}[label=\"mod\"]", graph.id)?;
+                writeln!(out, "{} -> {} [label=lhs]", graph.id, lhs.id)?;
+                writeln!(out, "{} -> {} [label=rhs]", graph.id, rhs.id)?;
+                dump_recursive(lhs, visited, out)?;
+                dump_recursive(rhs, visited, out)?;
+            }
+            NodeOp::Eql(lhs, rhs) => {
+                writeln!(out, "{}[label=\"eql\"]", graph.id)?;
+                writeln!(out, "{} -> {} [label=lhs]", graph.id, lhs.id)?;
+                writeln!(out, "{} -> {} [label=rhs]", graph.id, rhs.id)?;
+                dump_recursive(lhs, visited, out)?;
+                dump_recursive(rhs, visited, out)?;
+            }
+            NodeOp::Input => {
+                writeln!(out, "{}[label=\"input\"]", graph.id)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    let mut visited = HashSet::new();
+    let mut out = String::new();
+    writeln!(out, "digraph {{")?;
+
+    dump_recursive(graph, &mut visited, &mut out)?;
+
+    writeln!(out, "}}")?;
+    Ok(out)
+}
+
+/// The constraints of the program in the SMT-LIB language, which an SMT solver
+/// like z3 can check.
+fn smt_program(instructions: &[Instruction]) -> Result<String, fmt::Error> {
+    let mut out = String::new();
+    let mut current_vars = [0; 4];
+
+    let op_idx = |v: Operand| match v {
+        Operand::X => 0,
+        Operand::Y => 1,
+        Operand::Z => 2,
+        Operand::W => 3,
+        Operand::Number(_) => {
+            unreachable!("Assigned operands are validated while parsing.")
+        }
+    };
+
+    let var_str = |op: Operand, idx: u32| match op {
+        Operand::X => format!("x{}", idx),
+        Operand::Y => format!("y{}", idx),
+        Operand::Z => format!("z{}", idx),
+        Operand::W => format!("w{}", idx),
+        Operand::Number(_) => {
+            unreachable!("Assigned operands are validated while parsing.")
+        }
+    };
+
+    let op_str = |op: Operand, vars: &[u32; 4]| match op {
+        Operand::X | Operand::Y | Operand::Z | Operand::W => {
+            var_str(op, vars[op_idx(op)])
+        }
+        Operand::Number(val) => format!("{}", val),
+    };
+
+    writeln!(out, "(declare-const x0 Int)")?;
+    writeln!(out, "(declare-const y0 Int)")?;
+    writeln!(out, "(declare-const z0 Int)")?;
+    writeln!(out, "(declare-const w0 Int)")?;
+
+    for &instr in instructions {
+        writeln!(out, "; {:?}", instr)?;
+
+        match instr {
+            Instruction::Inp(o) => {
+                let i = &mut current_vars[op_idx(o)];
+                *i += 1;
+                writeln!(out, "(declare-const {} Int)", var_str(o, *i))?;
+                writeln!(out, "(assert (>= {} 1))", var_str(o, *i))?;
+                writeln!(out, "(assert (<= {} 9))", var_str(o, *i))?;
+            }
+            Instruction::Add(lhs, rhs) => {
+                let old_lhs_idx = current_vars[op_idx(lhs)];
+                let i = &mut current_vars[op_idx(lhs)];
+                *i += 1;
+
+                writeln!(out, "(declare-const {} Int)", var_str(lhs, *i))?;
+                writeln!(
+                    out,
+                    "(assert (= {} (+ {} {})))",
+                    var_str(lhs, *i),
+                    var_str(lhs, old_lhs_idx),
+                    op_str(rhs, &current_vars),
+                )?;
+            }
+            Instruction::Mul(lhs, rhs) => {
+                let old_lhs_idx = current_vars[op_idx(lhs)];
+                let i = &mut current_vars[op_idx(lhs)];
+                *i += 1;
+
+                writeln!(out, "(declare-const {} Int)", var_str(lhs, *i))?;
+                writeln!(
+                    out,
+                    "(assert (= {} (* {} {})))",
+                    var_str(lhs, *i),
+                    var_str(lhs, old_lhs_idx),
+                    op_str(rhs, &current_vars),
+                )?;
+            }
+            Instruction::Div(lhs, rhs) => {
+                let old_lhs_idx = current_vars[op_idx(lhs)];
+                let i = &mut current_vars[op_idx(lhs)];
+                *i += 1;
+
+                writeln!(out, "(declare-const {} Int)", var_str(lhs, *i))?;
+                writeln!(
+                    out,
+                    "(assert (= {} (div {} {})))",
+                    var_str(lhs, *i),
+                    var_str(lhs, old_lhs_idx),
+                    op_str(rhs, &current_vars),
+                )?;
+                writeln!(
+                    out,
+                    "(assert (not (= {} 0)))",
+                    op_str(rhs, &current_vars)
+                )?;
+            }
+            Instruction::Mod(lhs, rhs) => {
+                let old_lhs_idx = current_vars[op_idx(lhs)];
+                let i = &mut current_vars[op_idx(lhs)];
+                *i += 1;
+
+                writeln!(out, "(declare-const {} Int)", var_str(lhs, *i))?;
+                writeln!(
+                    out,
+                    "(assert (= {} (mod {} {})))",
+                    var_str(lhs, *i),
+                    var_str(lhs, old_lhs_idx),
+                    op_str(rhs, &current_vars),
+                )?;
+                writeln!(out, "(assert (> {} 0))", op_str(rhs, &current_vars))?;
+                writeln!(out, "(assert (>= {} 0))", var_str(lhs, old_lhs_idx))?;
+            }
+            Instruction::Eql(lhs, rhs) => {
+                let old_lhs_idx = current_vars[op_idx(lhs)];
+                let i = &mut current_vars[op_idx(lhs)];
+                *i += 1;
+
+                writeln!(out, "(declare-const {} Int)", var_str(lhs, *i))?;
+                writeln!(
+                    out,
+                    "(assert (= {} (if (= {} {}) 1 0)))",
+                    var_str(lhs, *i),
+                    var_str(lhs, old_lhs_idx),
+                    op_str(rhs, &current_vars),
+                )?;
+            }
+        }
+    }
+
+    writeln!(out, "(check-sat)")?;
+    writeln!(out, "(get-value ({}))", op_str(Operand::Z, &current_vars))?;
+    writeln!(out, "(exit)")?;
+
+    Ok(out)
+}
+
+/// Applies a binary instruction, `None` if the ALU can not compute it.
+fn alu(instruction: Instruction, lhs: i64, rhs: i64) -> Option<i64> {
+    match instruction {
+        Instruction::Inp(_) => None,
+        Instruction::Add(..) => lhs.checked_add(rhs),
+        Instruction::Mul(..) => lhs.checked_mul(rhs),
+        Instruction::Div(..) => lhs.checked_div(rhs),
+        Instruction::Mod(..) if lhs >= 0 && rhs > 0 => Some(lhs % rhs),
+        Instruction::Mod(..) => None,
+        Instruction::Eql(..) => Some(i64::from(lhs == rhs)),
+    }
+}
+
+/// Runs the program with `digits` as input and returns the final `z`.
+fn run(instructions: &[Instruction], digits: &[i64]) -> anyhow::Result<i64> {
+    let index = |v: Operand| match v {
+        Operand::X => 0,
+        Operand::Y => 1,
+        Operand::Z => 2,
+        Operand::W => 3,
+        Operand::Number(_) => {
+            unreachable!("Assigned operands are validated while parsing.")
+        }
+    };
+
+    let mut vars = [0; 4];
+    let mut input = digits.iter();
+
+    for &instr in instructions {
+        match instr {
+            Instruction::Inp(op) => {
+                vars[index(op)] = *input.next().ok_or(anyhow!(
+                    "The program reads more than {} digits.",
+                    digits.len()
+                ))?;
+            }
+            Instruction::Add(lhs, rhs)
+            | Instruction::Mul(lhs, rhs)
+            | Instruction::Div(lhs, rhs)
+            | Instruction::Mod(lhs, rhs)
+            | Instruction::Eql(lhs, rhs) => {
+                let l = vars[index(lhs)];
+                let r = match rhs {
+                    Operand::Number(n) => n,
+                    _ => vars[index(rhs)],
+                };
+
+                vars[index(lhs)] = alu(instr, l, r).ok_or(anyhow!(
+                    "The ALU can not compute {:?} of {} and {}.",
+                    instr,
+                    l,
+                    r
+                ))?;
+            }
+        }
+    }
+
+    Ok(vars[index(Operand::Z)])
+}
+
+/// MONAD checks a digit of the model number in each of its blocks.
+const DIGITS: usize = 14;
+const BLOCK_LENGTH: usize = 18;
+
+/// A block of MONAD, which treats `z` as a stack of base 26 numbers. It either
+/// pushes the digit plus `offset`, or pops the top and pushes nothing if the
+/// digit equals it plus `check`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Push { offset: i64 },
+    Pop { check: i64 },
+}
+
+fn parse_block(block: &[Instruction]) -> Option<Block> {
+    use Instruction::*;
+    use Operand::*;
+
+    let number = |i: usize| match block.get(i)? {
+        Div(_, Number(n)) | Add(_, Number(n)) => Some(*n),
+        _ => None,
+    };
+    let (div, check, offset) = (number(4)?, number(5)?, number(15)?);
+
+    #[rustfmt::skip]
+    let expected = [
+        Inp(W), Mul(X, Number(0)), Add(X, Z), Mod(X, Number(26)),
+        Div(Z, Number(div)), Add(X, Number(check)), Eql(X, W), Eql(X, Number(0)),
+        Mul(Y, Number(0)), Add(Y, Number(25)), Mul(Y, X), Add(Y, Number(1)),
+        Mul(Z, Y), Mul(Y, Number(0)), Add(Y, W), Add(Y, Number(offset)),
+        Mul(Y, X), Add(Z, Y),
+    ];
+    if block != expected {
+        return None;
+    }
+
+    // A pushed number has to be a single base 26 digit, which no digit of the
+    // model number can match in the same block.
+    match div {
+        1 if check > 9 && (0..26 - 9).contains(&offset) => {
+            Some(Block::Push { offset })
+        }
+        26 => Some(Block::Pop { check }),
+        _ => None,
+    }
+}
+
+fn parse_blocks(instructions: &[Instruction]) -> anyhow::Result<Vec<Block>> {
+    ensure!(
+        instructions.len() == DIGITS * BLOCK_LENGTH,
+        "The program has {} instructions instead of the {} of MONAD.",
+        instructions.len(),
+        DIGITS * BLOCK_LENGTH
+    );
+
+    instructions
+        .chunks(BLOCK_LENGTH)
+        .enumerate()
+        .map(|(i, block)| {
+            parse_block(block).ok_or(anyhow!(
+                "Block {} of the program does not check a digit like MONAD.",
+                i + 1
+            ))
+        })
+        .collect()
+}
+
+/// The largest or smallest digits which MONAD accepts. Every popping block
+/// has to match the digit of the block which pushed the top of the stack.
+fn model_number(blocks: &[Block], largest: bool) -> anyhow::Result<Vec<i64>> {
+    let mut digits = vec![0; blocks.len()];
+    let mut stack = Vec::new();
+
+    for (j, &block) in blocks.iter().enumerate() {
+        match block {
+            Block::Push { offset } => stack.push((j, offset)),
+            Block::Pop { check } => {
+                let (i, offset) = stack.pop().ok_or(anyhow!(
+                    "Digit {} does not match any earlier digit.",
+                    j + 1
+                ))?;
+
+                let delta = offset + check;
+                ensure!(
+                    delta.abs() <= 8,
+                    "Digits {} and {} can not differ by {}.",
+                    i + 1,
+                    j + 1,
+                    delta
+                );
+
+                digits[i] = if largest {
+                    9.min(9 - delta)
+                } else {
+                    1.max(1 - delta)
+                };
+                digits[j] = digits[i] + delta;
+            }
+        }
+    }
+
+    if let Some((i, _)) = stack.first() {
+        bail!("Digit {} is not matched by any later digit.", i + 1);
+    }
+
+    Ok(digits)
+}
+
+fn solve(instructions: &[Instruction], largest: bool) -> anyhow::Result<u64> {
+    if tracing::enabled!(Level::DEBUG) {
+        let program = smt_program(instructions)?;
+        debug!("SMT program of the instructions:\n{}", program);
+    }
+
+    let blocks = parse_blocks(instructions)?;
+    let digits = model_number(&blocks, largest)?;
+    let number = digits.iter().fold(0, |n, &d| n * 10 + d as u64);
+
+    // The digits are checked by running the program, so a wrong analysis of the
+    // blocks can not give a wrong answer.
+    let z = run(instructions, &digits)?;
+    ensure!(z == 0, "MONAD does not accept the model number {}.", number);
+
+    Ok(number)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: Day = Day(24);
+
+    type Input = Vec<Instruction>;
+    type First = u64;
+    type Second = u64;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u64> {
+        solve(input, true)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<u64> {
+        solve(input, false)
+    }
+
+    fn visualize(
+        input: &Self::Input,
+        _puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        let graph = build_graph_from_instructions(input);
+
+        canvas.draw(Frame::Dot(dump_graph(&graph)?))
+    }
+}
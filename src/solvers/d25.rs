@@ -1,44 +1,49 @@
-use anyhow::{bail, ensure};
+use std::convert::Infallible;
 
-use crate::grid::Grid;
+use anyhow::bail;
+use owo_colors::AnsiColors;
+
+use crate::{
+    grid::Grid,
+    simulation::{self, Simulation},
+    visualize::{Canvas, Cell, Frame},
+    Day, Puzzle, Solution,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Tile {
+pub enum Tile {
     Empty,
     Right,
     Down,
 }
 
 impl Tile {
-    fn to_char(&self) -> char {
+    fn to_char(self) -> char {
         match self {
             Tile::Empty => '.',
             Tile::Right => '>',
             Tile::Down => 'v',
         }
     }
+
+    fn to_cell(self) -> Cell {
+        match self {
+            Tile::Empty => Cell::plain(self.to_char()),
+            Tile::Right => Cell::colored(self.to_char(), AnsiColors::Cyan),
+            Tile::Down => Cell::colored(self.to_char(), AnsiColors::Yellow),
+        }
+    }
 }
 
 fn parse(input: &str) -> anyhow::Result<Grid<Tile>> {
-    let height = input.trim().lines().count();
-    ensure!(height > 0, "Grid is empty");
-    let width = input.trim().lines().next().unwrap().trim().chars().count();
-
-    let grid: Result<_, _> = input
-        .trim()
-        .lines()
-        .flat_map(|l| l.trim().chars())
-        .map(|c| match c {
-            '.' => Ok(Tile::Empty),
-            '>' => Ok(Tile::Right),
-            'v' => Ok(Tile::Down),
-            _ => bail!("Illegal tile '{}'", c),
-        })
-        .collect();
-
-    let grid = grid?;
-
-    Ok(Grid::from_rows_columns(width, height, grid))
+    let grid = Grid::parse(input, input, |c| match c {
+        '.' => Some(Tile::Empty),
+        '>' => Some(Tile::Right),
+        'v' => Some(Tile::Down),
+        _ => None,
+    })?;
+
+    Ok(grid)
 }
 
 struct State {
@@ -82,7 +87,6 @@ impl State {
         }
 
         std::mem::swap(&mut self.current, &mut self.scratch);
-        //print_grid(&self.current);
 
         self.scratch.fill(Tile::Empty);
 
@@ -113,20 +117,23 @@ impl State {
     }
 }
 
-#[allow(unused)]
-fn print_grid(grid: &Grid<Tile>) {
-    for row in grid.rows() {
-        for b in row {
-            print!("{}", b.to_char());
-        }
-        println!()
-    }
+fn grid_frame(grid: &Grid<Tile>) -> Frame {
+    Frame::from_grid(grid, |_, _, t| t.to_cell())
 }
 
-pub fn part1(text: &str) -> anyhow::Result<u32> {
-    let grid = parse(text)?;
+/// The herds move until they are stuck.
+impl Simulation for State {
+    fn step(&mut self) -> bool {
+        self.update()
+    }
+
+    fn frame(&self) -> Frame {
+        grid_frame(&self.current)
+    }
+}
 
-    let mut state = State::new(grid);
+fn part1(grid: &Grid<Tile>) -> anyhow::Result<u32> {
+    let mut state = State::new(grid.clone());
 
     let mut counter = 0;
 
@@ -136,3 +143,42 @@ pub fn part1(text: &str) -> anyhow::Result<u32> {
 
     Ok(counter + 1)
 }
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: Day = Day(25);
+
+    const PUZZLES: &'static [Puzzle] = &[Puzzle::First];
+
+    type Input = Grid<Tile>;
+    type First = u32;
+    type Second = Infallible;
+
+    fn parse(text: &str) -> anyhow::Result<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<u32> {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input) -> anyhow::Result<Infallible> {
+        bail!("There is no second puzzle on day 25.")
+    }
+
+    fn visualize(
+        input: &Self::Input,
+        _puzzle: Puzzle,
+        canvas: &mut dyn Canvas,
+    ) -> anyhow::Result<()> {
+        simulation::record(&mut State::new(input.clone()), canvas)
+    }
+
+    fn simulation(
+        input: &Self::Input,
+        _puzzle: Puzzle,
+    ) -> anyhow::Result<Box<dyn Simulation + '_>> {
+        Ok(Box::new(State::new(input.clone())))
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use anyhow::Context;
+use owo_colors::{AnsiColors, OwoColorize, Style};
+
+use crate::grid::Grid;
+
+/// Picture of an intermediate state of a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Frame {
+    /// Rows of colored characters.
+    Grid(Vec<Vec<Cell>>),
+    /// Graph in the dot language of graphviz.
+    Dot(String),
+}
+
+/// Character of a grid frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub color: Option<AnsiColors>,
+    pub bold: bool,
+}
+
+impl Cell {
+    pub fn plain(symbol: char) -> Self {
+        Self {
+            symbol,
+            color: None,
+            bold: false,
+        }
+    }
+
+    pub fn colored(symbol: char, color: AnsiColors) -> Self {
+        Self {
+            color: Some(color),
+            ..Self::plain(symbol)
+        }
+    }
+
+    pub fn bold(self) -> Self {
+        Self { bold: true, ..self }
+    }
+
+    fn style(&self) -> Style {
+        let style = match self.color {
+            Some(color) => Style::new().color(color),
+            None => Style::new(),
+        };
+
+        if self.bold {
+            style.bold()
+        } else {
+            style
+        }
+    }
+}
+
+impl Frame {
+    /// Frame with a cell for every tile of `grid`.
+    pub(crate) fn from_grid<T>(
+        grid: &Grid<T>,
+        mut cell: impl FnMut(i32, i32, &T) -> Cell,
+    ) -> Self {
+        let rows = grid
+            .rows()
+            .enumerate()
+            .map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(x, t)| cell(x as i32, y as i32, t))
+                    .collect()
+            })
+            .collect();
+
+        Frame::Grid(rows)
+    }
+
+    /// The frame without any colors.
+    pub fn to_plain_text(&self) -> String {
+        match self {
+            Frame::Grid(rows) => rows
+                .iter()
+                .map(|row| row.iter().map(|c| c.symbol).collect::<String>() + "\n")
+                .collect(),
+            Frame::Dot(dot) => dot.clone(),
+        }
+    }
+
+    /// The frame with the colors as terminal escape codes.
+    pub fn to_colored_text(&self) -> String {
+        match self {
+            Frame::Grid(rows) => {
+                let mut text = String::new();
+                for row in rows {
+                    for cell in row {
+                        // Writing into a string can not fail.
+                        let _ = write!(text, "{}", cell.symbol.style(cell.style()));
+                    }
+                    text.push('\n');
+                }
+                text
+            }
+            Frame::Dot(dot) => dot.clone(),
+        }
+    }
+
+    /// File extension used when the frame is written to a file.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Frame::Grid(_) => "txt",
+            Frame::Dot(_) => "dot",
+        }
+    }
+}
+
+/// Receives the frames emitted while solving a puzzle.
+pub trait Canvas {
+    fn draw(&mut self, frame: Frame) -> anyhow::Result<()>;
+
+    /// Called after the last frame, e.g. to write frames which were collected.
+    fn finish(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// Collects the frames, e.g. to inspect them in tests.
+impl Canvas for Vec<Frame> {
+    fn draw(&mut self, frame: Frame) -> anyhow::Result<()> {
+        self.push(frame);
+        Ok(())
+    }
+}
+
+/// Prints every frame with a numbered header.
+pub struct Terminal<W> {
+    out: W,
+    frames: usize,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, frames: 0 }
+    }
+}
+
+impl Terminal<io::Stdout> {
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> Canvas for Terminal<W> {
+    fn draw(&mut self, frame: Frame) -> anyhow::Result<()> {
+        writeln!(self.out, "{}", format!("Frame {}", self.frames).dimmed())?;
+        write!(self.out, "{}", frame.to_colored_text())?;
+        writeln!(self.out)?;
+        self.out.flush()?;
+
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+/// Writes every frame to its own file `frame_NNNN.<ext>` in a directory.
+pub struct Directory {
+    path: PathBuf,
+    frames: usize,
+}
+
+impl Directory {
+    /// Creates the directory if it does not exist yet.
+    pub fn create(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        fs::create_dir_all(&path)
+            .with_context(|| format!("Can not create '{}'", path.display()))?;
+
+        Ok(Self { path, frames: 0 })
+    }
+}
+
+impl Canvas for Directory {
+    fn draw(&mut self, frame: Frame) -> anyhow::Result<()> {
+        let file = self.path.join(format!(
+            "frame_{:04}.{}",
+            self.frames,
+            frame.extension()
+        ));
+
+        fs::write(&file, frame.to_plain_text())
+            .with_context(|| format!("Can not write '{}'", file.display()))?;
+
+        self.frames += 1;
+        Ok(())
+    }
+}
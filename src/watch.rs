@@ -0,0 +1,149 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use anyhow::Context;
+use owo_colors::OwoColorize;
+
+use crate::{
+    answers::{self, Answers, Expected},
+    solve_with, Answer, Day, Params, Puzzle,
+};
+
+/// How often the watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A puzzle which is solved again whenever its input or the answer manifest
+/// changes.
+#[derive(Debug, Clone)]
+pub struct Watch {
+    pub day: Day,
+    pub puzzle: Puzzle,
+    pub input: PathBuf,
+    /// Manifest with the expected answer, the input is looked up by its file name.
+    pub answers: Option<PathBuf>,
+    /// Parameters changed with `--param`.
+    pub params: Params,
+}
+
+/// Outcome of solving the watched puzzle once.
+pub struct WatchReport {
+    pub answer: anyhow::Result<Answer>,
+    /// Wall time of reading the input, parsing it and solving the puzzle.
+    pub time: Duration,
+    /// The expected answer, unless the manifest has none for the input.
+    pub expected: anyhow::Result<Option<Expected>>,
+}
+
+impl WatchReport {
+    /// Whether the answer is the expected one, `None` without an expected answer.
+    pub fn passed(&self) -> Option<bool> {
+        match (&self.answer, &self.expected) {
+            (Ok(answer), Ok(Some(expected))) => {
+                Some(answer.to_string() == expected.answer)
+            }
+            (Err(_), Ok(Some(_))) => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Modification time and size of a file, `None` while it does not exist.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+impl Watch {
+    /// Solves the puzzle and prints the report every time a watched file changes,
+    /// until the process is stopped.
+    pub fn run(&self, mut out: impl Write) -> anyhow::Result<()> {
+        loop {
+            let stamps = self.stamps();
+
+            let report = self.run_once();
+            write!(out, "\x1b[H\x1b[2J")?;
+            self.print_report(&report, &mut out)?;
+            writeln!(out, "\nWatching for changes, press Ctrl-C to stop.")?;
+            out.flush()?;
+
+            while self.stamps() == stamps {
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+    }
+
+    fn stamps(&self) -> Vec<Stamp> {
+        let mut stamps = vec![stamp(&self.input)];
+        if let Some(answers) = &self.answers {
+            stamps.push(stamp(answers));
+        }
+        stamps
+    }
+
+    pub fn run_once(&self) -> WatchReport {
+        let start = Instant::now();
+        let answer = fs::read_to_string(&self.input)
+            .with_context(|| format!("Can not read '{}'", self.input.display()))
+            .and_then(|text| solve_with(self.day, self.puzzle, &text, &self.params));
+        let time = start.elapsed();
+
+        WatchReport {
+            answer,
+            time,
+            expected: self.expected(),
+        }
+    }
+
+    fn expected(&self) -> anyhow::Result<Option<Expected>> {
+        let (path, (_, name)) =
+            match (&self.answers, answers::parse_input_name(&self.input)) {
+                (Some(path), Some(input)) => (path, input),
+                _ => return Ok(None),
+            };
+
+        let manifest = Answers::load(path)?;
+        Ok(manifest.get(self.day, &name, self.puzzle).cloned())
+    }
+
+    /// Prints the answer with its time, and whether it is the expected one.
+    pub fn print_report(
+        &self,
+        report: &WatchReport,
+        mut out: impl Write,
+    ) -> io::Result<()> {
+        writeln!(
+            out,
+            "Day {}, {} puzzle, {}\n",
+            self.day,
+            self.puzzle,
+            self.input.display()
+        )?;
+
+        match &report.answer {
+            Ok(answer) => writeln!(out, "{}", answer)?,
+            Err(e) => writeln!(out, "{} {:#}", "error:".red(), e)?,
+        }
+        writeln!(out, "\nSolved in {:.2?}", report.time)?;
+
+        match (&report.expected, report.passed()) {
+            (Err(e), _) => writeln!(out, "{} {:#}", "answers:".red(), e)?,
+            (Ok(None), _) => writeln!(out, "No expected answer")?,
+            (Ok(Some(_)), Some(true)) => writeln!(out, "{}", "PASS".green())?,
+            (Ok(Some(expected)), _) => {
+                writeln!(out, "{}", "FAIL".red())?;
+                if let Ok(answer) = &report.answer {
+                    answers::write_diff(&expected.answer, &answer.to_string(), out)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
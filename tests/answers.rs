@@ -0,0 +1,53 @@
+use aoc2021::{
+    answers::{parse_input_name, Answers},
+    Day, Puzzle,
+};
+
+#[test]
+fn manifest_values() -> anyhow::Result<()> {
+    let answers = Answers::from_toml(
+        r#"
+        [d01.small]
+        first = 7
+        second = { answer = "a\nb", slow = true }
+        "#,
+    )?;
+
+    let day = Day::new(1)?;
+
+    let first = answers.get(day, "small", Puzzle::First).unwrap();
+    assert_eq!(first.answer, "7");
+    assert!(!first.slow);
+
+    let second = answers.get(day, "small", Puzzle::Second).unwrap();
+    assert_eq!(second.answer, "a\nb");
+    assert!(second.slow);
+
+    assert!(answers.get(day, "main", Puzzle::First).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_day_key() {
+    assert!(Answers::from_toml("[day1.small]\nfirst = 1").is_err());
+    assert!(Answers::from_toml("[d30.small]\nfirst = 1").is_err());
+}
+
+#[test]
+fn unknown_fields_are_rejected() {
+    let error = |toml| format!("{:#}", Answers::from_toml(toml).unwrap_err());
+
+    assert!(error("[d01.small]\nseccond = 5").contains("unknown field `seccond`"));
+    assert!(error("[d01.small]\nfirst = { answer = 5, slo = true }")
+        .contains("unknown field `slo`"));
+}
+
+#[test]
+fn input_names() {
+    assert_eq!(
+        parse_input_name("res/d16_eval_1.txt".as_ref()),
+        Some((Day::new(16).unwrap(), "eval_1".to_string()))
+    );
+    assert_eq!(parse_input_name("input.txt".as_ref()), None);
+}
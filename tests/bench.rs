@@ -0,0 +1,40 @@
+use aoc2021::{
+    bench::{run_bench, BenchConfig, BenchReport, Stage},
+    inputs::Inputs,
+    Day,
+};
+
+#[test]
+fn bench_measures_every_stage() -> anyhow::Result<()> {
+    let config = BenchConfig {
+        warmup: 1,
+        iterations: 5,
+    };
+    let report = run_bench(&"1,25".parse()?, &Inputs::default(), "small", &config)?;
+
+    let stages: Vec<_> = report
+        .measurements
+        .iter()
+        .map(|m| (m.day, m.stage))
+        .collect();
+    assert_eq!(
+        stages,
+        [
+            (Day::new(1)?, Stage::Parse),
+            (Day::new(1)?, Stage::First),
+            (Day::new(1)?, Stage::Second),
+            (Day::new(25)?, Stage::Parse),
+            (Day::new(25)?, Stage::First),
+        ]
+    );
+    assert!(report
+        .measurements
+        .iter()
+        .all(|m| m.min_ns <= m.median_ns && m.median_ns <= m.p95_ns));
+
+    let json = serde_json::to_string(&report)?;
+    let loaded: BenchReport = serde_json::from_str(&json)?;
+    assert_eq!(loaded.measurements.len(), report.measurements.len());
+
+    Ok(())
+}
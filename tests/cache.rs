@@ -0,0 +1,128 @@
+use std::{path::Path, time::Duration};
+
+use aoc2021::{
+    cache::{self, Cache, CacheKey, Cached},
+    inputs::Inputs,
+    runner::run_days,
+    Answer, Day, Puzzle,
+};
+
+#[test]
+fn cache_is_saved_and_invalidated_per_day() -> anyhow::Result<()> {
+    let path = std::env::temp_dir()
+        .join(format!("aoc2021-cache-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut cache = Cache::load(&path)?;
+    assert!(cache.is_empty());
+
+    let reports = run_days(&"1,6".parse()?, &Inputs::default(), "small");
+    for report in &reports {
+        cache.store(report);
+    }
+    cache.save()?;
+
+    let mut cache = Cache::load(&path)?;
+    assert_eq!(cache.len(), 4);
+
+    let key = CacheKey::for_file(
+        Day::new(6)?,
+        Puzzle::First,
+        Path::new("res/d06_small.txt"),
+    )
+    .unwrap();
+    assert_eq!(
+        cache.get(&key).map(|c| &c.answer),
+        Some(&Answer::Integer(5934))
+    );
+
+    let other_version = CacheKey {
+        version: "0.0.0".to_string(),
+        ..key.clone()
+    };
+    assert_eq!(cache.get(&other_version), None);
+
+    assert_eq!(cache.invalidate(Some(Day::new(6)?)), 2);
+    assert_eq!(cache.get(&key), None);
+    assert_eq!(cache.invalidate(None), 2);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn only_new_answers_are_cached() -> anyhow::Result<()> {
+    let mut cache = Cache::default();
+
+    let reports = run_days(&"1".parse()?, &Inputs::default(), "missing");
+    for report in &reports {
+        cache.store(report);
+    }
+    assert!(cache.is_empty());
+
+    let key = CacheKey::new(Day::new(1)?, Puzzle::First, "1\n2\n");
+    cache.insert(
+        key.clone(),
+        Cached {
+            answer: Answer::Integer(1),
+            parse_time: None,
+            time: Duration::from_secs(1),
+        },
+    );
+    let path = std::env::temp_dir()
+        .join(format!("aoc2021-input-{}.txt", std::process::id()));
+    std::fs::write(&path, "1\n2\n")?;
+
+    let report = cache.lookup(key.day, key.puzzle, &path).unwrap();
+    assert!(report.cached);
+    assert_eq!(report.time, Duration::from_secs(1));
+
+    let mut other = Cache::default();
+    other.store(&report);
+    assert!(other.is_empty());
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn answers_are_cached_for_the_input_which_was_solved() -> anyhow::Result<()> {
+    let dir =
+        std::env::temp_dir().join(format!("aoc2021-solved-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let inputs = Inputs::new(&dir);
+    let path = inputs.path(Day::new(1)?, "small");
+    std::fs::write(&path, "1\n2\n")?;
+
+    let reports = run_days(&"1".parse()?, &inputs, "small");
+
+    // The input changes after it was solved.
+    std::fs::write(&path, "3\n2\n1\n")?;
+    let mut cache = Cache::default();
+    for report in &reports {
+        cache.store(report);
+    }
+
+    let solved = CacheKey::new(Day::new(1)?, Puzzle::First, "1\n2\n");
+    assert_eq!(
+        cache.get(&solved).map(|c| &c.answer),
+        Some(&Answer::Integer(1))
+    );
+    assert!(cache.lookup(Day::new(1)?, Puzzle::First, &path).is_none());
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+fn cache_is_kept_in_the_inputs_directory() {
+    if std::env::var_os(cache::PATH_VAR).is_some() {
+        return;
+    }
+
+    assert_eq!(
+        cache::path(&Inputs::new("res")),
+        Some(Path::new("res").join(cache::FILE_NAME))
+    );
+    assert_eq!(cache::path(&Inputs::new("no-such-inputs")), None);
+}
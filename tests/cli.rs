@@ -0,0 +1,158 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A path for an answer cache which no other run uses.
+fn cache_path() -> PathBuf {
+    static CACHES: AtomicUsize = AtomicUsize::new(0);
+
+    std::env::temp_dir().join(format!(
+        "aoc2021-cli-cache-{}-{}.json",
+        std::process::id(),
+        CACHES.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+fn aoc2021_with_cache(
+    args: &[&str],
+    cache: &Path,
+) -> anyhow::Result<(String, String)> {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc2021"))
+        .args(args)
+        .env("AOC2021_CACHE", cache)
+        .output()?;
+    anyhow::ensure!(output.status.success(), "aoc2021 {:?} failed", args);
+
+    Ok((
+        String::from_utf8(output.stdout)?,
+        String::from_utf8(output.stderr)?,
+    ))
+}
+
+/// Runs the binary with an empty answer cache of its own.
+fn aoc2021(args: &[&str]) -> anyhow::Result<(String, String)> {
+    let cache = cache_path();
+    let output = aoc2021_with_cache(args, &cache);
+    let _ = std::fs::remove_file(&cache);
+
+    output
+}
+
+#[test]
+fn stdout_only_has_the_answer() -> anyhow::Result<()> {
+    let (stdout, stderr) = aoc2021(&["-d", "22", "-p", "2", "-i", "small", "-vv"])?;
+    assert_eq!(stdout, "39769202357779\n");
+    assert!(stderr.contains("processed the cuboids"));
+    assert!(stderr.contains("solve{day=22 puzzle=Second}"));
+
+    let (stdout, stderr) = aoc2021(&["-d", "24"])?;
+    assert_eq!(stdout, "99799212949967\n");
+    assert_eq!(stderr, "");
+
+    Ok(())
+}
+
+#[test]
+fn params_change_the_answer() -> anyhow::Result<()> {
+    let (stdout, _) = aoc2021(&["-d", "6", "-i", "small", "--param", "days=18"])?;
+    assert_eq!(stdout, "26\n");
+
+    let (stdout, _) = aoc2021(&["params", "-d", "21"])?;
+    assert_eq!(stdout.lines().count(), 2);
+    assert!(stdout.contains("goal"));
+    assert!(stdout.contains("board"));
+
+    Ok(())
+}
+
+#[test]
+fn json_has_a_record_per_puzzle() -> anyhow::Result<()> {
+    let (stdout, _) =
+        aoc2021(&["--days", "1,25", "--suffix", "small", "--format", "json"])?;
+
+    let records: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[2]["day"], 25);
+    assert_eq!(records[2]["answer"]["value"], 58);
+    assert_eq!(records[2]["input"], "res/d25_small.txt");
+
+    Ok(())
+}
+
+#[test]
+fn answers_are_cached_until_invalidated() -> anyhow::Result<()> {
+    let cache = cache_path();
+    let run = |args: &[&str]| -> anyhow::Result<serde_json::Value> {
+        let (stdout, _) = aoc2021_with_cache(args, &cache)?;
+        Ok(serde_json::from_str(&stdout)?)
+    };
+    let args = ["-d", "1", "-i", "small", "--format", "json"];
+
+    let first = run(&args)?;
+    let second = run(&args)?;
+    assert_eq!(first["cached"], false);
+    assert_eq!(second["cached"], true);
+    assert_eq!(second["answer"], first["answer"]);
+    assert_eq!(second["time_ns"], first["time_ns"]);
+
+    assert_eq!(
+        run(&[&args[..], &["--no-cache"]].concat())?["cached"],
+        false
+    );
+    assert_eq!(run(&[&args[..], &["-p", "2"]].concat())?["cached"], false);
+
+    let (stdout, _) = aoc2021_with_cache(&["invalidate", "-d", "2"], &cache)?;
+    assert_eq!(stdout, "Removed 0 cached answers.\n");
+    assert_eq!(run(&args)?["cached"], true);
+
+    let (stdout, _) = aoc2021_with_cache(&["invalidate", "-d", "1"], &cache)?;
+    assert_eq!(stdout, "Removed 2 cached answers.\n");
+    assert_eq!(run(&args)?["cached"], false);
+
+    std::fs::remove_file(&cache)?;
+    Ok(())
+}
+
+#[test]
+fn unwritable_caches_only_warn() -> anyhow::Result<()> {
+    let cache = Path::new("/nonexistent/dir/cache.json");
+
+    let (stdout, stderr) = aoc2021_with_cache(&["-d", "1", "-i", "small"], cache)?;
+    assert_eq!(stdout, "7\n");
+    assert!(stderr.starts_with("Warning: Can not write"));
+
+    let (stdout, stderr) = aoc2021_with_cache(&["--days", "1"], cache)?;
+    assert!(stdout.contains("1797"));
+    assert!(stderr.starts_with("Warning: Can not write"));
+
+    Ok(())
+}
+
+#[test]
+fn runs_outside_of_the_project_leave_no_cache() -> anyhow::Result<()> {
+    let dir =
+        std::env::temp_dir().join(format!("aoc2021-cli-cwd-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc2021"))
+        .args(["-d", "1", "--text", "1\n2\n3\n"])
+        .current_dir(&dir)
+        .env_remove("AOC2021_CACHE")
+        .env_remove("AOC2021_INPUTS")
+        .output()?;
+    let entries = std::fs::read_dir(&dir)?.count();
+    std::fs::remove_dir_all(&dir)?;
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout)?, "2\n");
+    assert_eq!(entries, 0);
+
+    Ok(())
+}
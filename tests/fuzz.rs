@@ -0,0 +1,27 @@
+use std::{fs, str};
+
+use aoc2021::{solver, Day};
+
+/// Inputs which crashed the fuzz targets, in a directory named like the target.
+const REGRESSIONS: &str = "fuzz/regressions";
+
+#[test]
+fn fuzz_regressions_are_replayed() -> anyhow::Result<()> {
+    for dir in fs::read_dir(REGRESSIONS)? {
+        let dir = dir?.path();
+        let target = dir.file_name().unwrap().to_string_lossy();
+        let day: Day = target.trim_start_matches('d').parse()?;
+        let solver = solver(day).unwrap();
+
+        for input in fs::read_dir(&dir)? {
+            let data = fs::read(input?.path())?;
+
+            // Like the fuzz targets, only a crash is a failure, errors are fine.
+            if let Ok(text) = str::from_utf8(&data) {
+                let _ = solver.parse(text);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use aoc2021::geom::{
+    Axis, AxisRange, BoundingBox, Direction, Point, Point2, Point3,
+};
+
+#[test]
+fn distances_between_points() {
+    let a = Point3::new(1, -2, 3);
+    let b = Point3::new(-4, 2, 3);
+
+    assert_eq!(a.manhattan(b), 9);
+    assert_eq!(a.chebyshev(b), 5);
+    assert_eq!(b - a, Point3::new(-5, 4, 0));
+    assert_eq!(-a * 2, Point3::new(-2, 4, -6));
+    assert_eq!(a[Axis::Z], 3);
+
+    // Unsigned coordinates do not underflow.
+    assert_eq!(Point2::new(1u16, 7).manhattan(Point2::new(3, 2)), 7);
+    assert_eq!(Point([0u8; 4]).chebyshev(Point([1, 9, 2, 0])), 9);
+}
+
+#[test]
+fn neighbors_on_a_grid() {
+    let p = Point2::new(5, 5);
+
+    let neighbors: Vec<_> = p.neighbors().collect();
+    assert_eq!(
+        neighbors,
+        [
+            Point2::new(5, 4),
+            Point2::new(6, 5),
+            Point2::new(5, 6),
+            Point2::new(4, 5)
+        ]
+    );
+    for (direction, n) in Direction::ALL.into_iter().zip(&neighbors) {
+        assert_eq!(*n + direction.opposite().offset(), p);
+    }
+
+    assert_eq!(p.adjacent().count(), 8);
+    assert!(p.adjacent().all(|n| n.chebyshev(p) == 1));
+
+    // The window goes row by row, so its pixels can be read as a number.
+    let window: Vec<_> = p.window().collect();
+    assert_eq!(window[0], Point2::new(4, 4));
+    assert_eq!(window[1], Point2::new(5, 4));
+    assert_eq!(window[4], p);
+    assert_eq!(window[8], Point2::new(6, 6));
+}
+
+#[test]
+fn axis_ranges_are_inclusive() {
+    let range = AxisRange::new(-2, 3);
+
+    assert_eq!(range.len(), Some(6));
+    assert!(range.contains(-2) && range.contains(3) && !range.contains(4));
+    assert_eq!(range.into_iter().count(), 6);
+    assert_eq!(
+        range.intersection(&AxisRange::new(3, 10)),
+        Some(AxisRange::new(3, 3))
+    );
+    assert_eq!(range.intersection(&AxisRange::new(4, 10)), None);
+
+    assert!(AxisRange::new(1, 0).is_empty());
+    assert_eq!(AxisRange::new(1, 0).len(), Some(0));
+    assert_eq!(AxisRange::new(i8::MIN, i8::MAX).len(), None);
+}
+
+#[test]
+fn bounding_boxes_cover_their_points() {
+    let points = [
+        Point3::new(1, 5, -1),
+        Point3::new(-3, 2, 0),
+        Point3::new(0, 0, 4),
+    ];
+    let bounds = BoundingBox::from_points(points).unwrap();
+
+    assert_eq!(bounds.min, Point3::new(-3, 0, -1));
+    assert_eq!(bounds.max, Point3::new(1, 5, 4));
+    assert!(points.iter().all(|&p| bounds.contains(p)));
+    assert!(!bounds.contains(Point3::new(2, 0, 0)));
+    assert_eq!(bounds.axis(Axis::Y.index()), AxisRange::new(0, 5));
+
+    let inner = BoundingBox::from_ranges([AxisRange::new(0, 1); 3]);
+    assert!(bounds.contains_box(&inner));
+    assert!(!inner.contains_box(&bounds));
+    assert_eq!(bounds.intersection(&inner), Some(inner));
+
+    assert_eq!(BoundingBox::<i32, 2>::from_points([]), None);
+}
@@ -0,0 +1,39 @@
+use aoc2021::{inputs::Inputs, Day};
+
+#[test]
+fn inputs_are_found_by_name() -> anyhow::Result<()> {
+    let inputs = Inputs::new("res");
+
+    assert_eq!(
+        inputs.find(Day::new(7)?, "small")?,
+        std::path::Path::new("res/d07_small.txt")
+    );
+    assert_eq!(inputs.variants(Day::new(7)?)?, ["main", "small"]);
+
+    let err = inputs.find(Day::new(7)?, "large").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "There is no input 'large' for day 7 in 'res', available: main, small."
+    );
+
+    Ok(())
+}
+
+#[test]
+fn every_day_has_a_main_input() -> anyhow::Result<()> {
+    let list = Inputs::default().list()?;
+
+    assert_eq!(list.len(), 25);
+    assert!(list.values().all(|names| names.iter().any(|n| n == "main")));
+    assert!(list[&Day::new(23)?].contains(&"small_second".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn missing_directories_are_an_error() {
+    let inputs = Inputs::new("does/not/exist");
+
+    assert!(inputs.list().is_err());
+    assert!(inputs.find(Day::new(1).unwrap(), "main").is_err());
+}
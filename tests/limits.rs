@@ -0,0 +1,66 @@
+#![cfg(all(unix, feature = "cli"))]
+
+use std::time::Duration;
+
+use aoc2021::{
+    inputs::Inputs,
+    runner::{run_days_parallel, LimitError, Limits, Report, RunConfig},
+    Answer, Day, Puzzle,
+};
+
+fn run(days: &str, timeout: Option<Duration>, memory: Option<u64>) -> Vec<Report> {
+    let config = RunConfig {
+        limits: Some(Limits {
+            program: env!("CARGO_BIN_EXE_aoc2021").into(),
+            timeout,
+            memory,
+        }),
+        ..RunConfig::default()
+    };
+
+    run_days_parallel(&days.parse().unwrap(), &Inputs::default(), "main", &config)
+}
+
+fn limit_error(report: &Report) -> Option<&LimitError> {
+    report.answer.as_ref().err()?.downcast_ref()
+}
+
+#[test]
+fn puzzles_are_solved_by_workers() -> anyhow::Result<()> {
+    let reports = run("1", Some(Duration::from_secs(60)), Some(1 << 30));
+
+    let answers: Vec<_> = reports
+        .iter()
+        .map(|r| (r.day, r.puzzle, r.answer.as_ref().ok().cloned()))
+        .collect();
+    assert_eq!(
+        answers,
+        [
+            (Day::new(1)?, Puzzle::First, Some(Answer::Integer(1766))),
+            (Day::new(1)?, Puzzle::Second, Some(Answer::Integer(1797))),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn slow_puzzles_time_out() {
+    let reports = run("23", Some(Duration::from_millis(300)), None);
+
+    assert!(matches!(
+        limit_error(&reports[1]),
+        Some(LimitError::TimedOut(_))
+    ));
+    assert!(reports[1].time < Duration::from_secs(10));
+}
+
+#[test]
+fn allocations_are_limited() {
+    let reports = run("23", Some(Duration::from_secs(300)), Some(32 << 20));
+
+    assert!(matches!(
+        limit_error(&reports[1]),
+        Some(LimitError::OutOfMemory(_))
+    ));
+}
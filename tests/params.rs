@@ -0,0 +1,127 @@
+use aoc2021::{solve, solve_with, solvers, Answer, Day, Params, Puzzle};
+
+fn input(day: u32, name: &str) -> String {
+    std::fs::read_to_string(format!("res/d{:02}_{}.txt", day, name)).unwrap()
+}
+
+fn solve_small(
+    day: u32,
+    puzzle: Puzzle,
+    params: &[(&str, &str)],
+) -> anyhow::Result<Answer> {
+    let params: Params = params
+        .iter()
+        .map(|&(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+    solve_with(
+        Day::new(day).unwrap(),
+        puzzle,
+        &input(day, "small"),
+        &params,
+    )
+}
+
+#[test]
+fn params_change_the_puzzle() -> anyhow::Result<()> {
+    assert_eq!(
+        solve_small(6, Puzzle::First, &[("days", "18")])?,
+        Answer::from(26)
+    );
+    assert_eq!(
+        solve_small(6, Puzzle::Second, &[("days", "80")])?,
+        Answer::from(5934)
+    );
+    assert_eq!(
+        solve_small(11, Puzzle::First, &[("steps", "10")])?,
+        Answer::from(204)
+    );
+    assert_eq!(
+        solve_small(14, Puzzle::Second, &[("steps", "10")])?,
+        Answer::from(1588)
+    );
+    // Any roll wins the game for the first player.
+    assert_eq!(
+        solve_small(21, Puzzle::Second, &[("goal", "1")])?,
+        Answer::from(27)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn default_params_give_the_usual_answers() -> anyhow::Result<()> {
+    for solver in solvers().filter(|s| !s.params().is_empty()) {
+        for &puzzle in solver.puzzles() {
+            let defaults = Params::defaults(solver.params(), puzzle);
+            let text = input(u32::from(solver.day()), "small");
+
+            assert_eq!(
+                solve_with(solver.day(), puzzle, &text, &defaults)?,
+                solve(solver.day(), puzzle, &text)?,
+                "day {}, {} puzzle",
+                solver.day(),
+                puzzle
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn larger_region_initializes_every_cube() -> anyhow::Result<()> {
+    let text = input(22, "small");
+    let all = solve(Day::new(22).unwrap(), Puzzle::Second, &text)?;
+
+    let params: Params = [("region".to_string(), "1000000".to_string())]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        solve_with(Day::new(22).unwrap(), Puzzle::First, &text, &params)?,
+        all
+    );
+
+    Ok(())
+}
+
+#[test]
+fn counts_grow_beyond_64_bits() -> anyhow::Result<()> {
+    let fish = solve_small(6, Puzzle::Second, &[("days", "10000")])?;
+    assert!(matches!(&fish, Answer::BigInteger(n) if n.to_string().len() > 300));
+
+    assert!(matches!(
+        solve_small(14, Puzzle::First, &[("steps", "100")])?,
+        Answer::BigInteger(_)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_params_are_rejected() {
+    let error = |day, puzzle, params| {
+        solve_small(day, puzzle, params).unwrap_err().to_string()
+    };
+
+    assert_eq!(
+        error(6, Puzzle::First, &[("steps", "10")]),
+        "Day 6 has no parameter 'steps' in the First puzzle, available: days."
+    );
+    assert_eq!(
+        error(22, Puzzle::Second, &[("region", "10")]),
+        "Day 22 has no parameter 'region' in the Second puzzle, available: none."
+    );
+    assert_eq!(
+        error(6, Puzzle::First, &[("days", "-1")]),
+        "Invalid value '-1' of parameter 'days': invalid digit found in string."
+    );
+    assert_eq!(
+        error(21, Puzzle::First, &[("board", "5")]),
+        "Starting position 8 is not on the board of 5 spaces."
+    );
+    assert_eq!(
+        error(21, Puzzle::Second, &[("goal", "100")]),
+        "The universes do not fit into 64 bits."
+    );
+}
@@ -0,0 +1,110 @@
+use aoc2021::{Day, Location, ParseError, Puzzle};
+
+fn parse_error(day: u32, text: &str) -> ParseError {
+    let error = aoc2021::solve(Day::new(day).unwrap(), Puzzle::First, text)
+        .expect_err("invalid input was accepted");
+
+    match error.downcast() {
+        Ok(error) => error,
+        Err(error) => panic!("day {}: not a parse error: {}", day, error),
+    }
+}
+
+#[test]
+fn errors_point_at_the_input() {
+    let text = "199\n2x0\n208\n";
+    let error = parse_error(1, text);
+
+    assert_eq!(error.message(), "Invalid number '2x0'");
+    assert_eq!(&text[error.span()], "2x0");
+    assert_eq!(error.location(), Location { line: 2, column: 1 });
+    assert_eq!(
+        error.to_string(),
+        "Invalid number '2x0' at line 2, column 1.\n  |\n2 | 2x0\n  | ^^^"
+    );
+}
+
+#[test]
+fn snippets_underline_the_span() {
+    let error = parse_error(2, "forward 5\ndown 5\nforwad 8\n");
+    assert_eq!(error.snippet(), "  |\n3 | forwad 8\n  | ^^^^^^");
+
+    let error = parse_error(16, "8A00G");
+    assert_eq!(error.snippet(), "  |\n1 | 8A00G\n  |     ^");
+
+    let error = parse_error(18, "[[3,4],x]\n");
+    assert_eq!(error.snippet(), "  |\n1 | [[3,4],x]\n  |        ^");
+
+    // Missing parts are pointed at the end of the input.
+    let error = parse_error(13, "6,10\n0,14\n");
+    assert_eq!(error.location(), Location { line: 2, column: 5 });
+    assert_eq!(error.snippet(), "  |\n2 | 0,14\n  |     ^");
+
+    // Lines above 9 widen the margin.
+    let text = format!("{}x\n", "1\n".repeat(11));
+    let error = parse_error(1, &text);
+    assert_eq!(error.snippet(), "   |\n12 | x\n   | ^");
+}
+
+#[test]
+fn every_parser_reports_spans() {
+    let inputs = [
+        (3, "00100\n11110\n1011\n"),
+        (4, "7,4,9\n\n22 13 17 11  0\n 8  2 23  4 24\n"),
+        (5, "0,9 -> 5,9\n8,0 -> 0,9\n"),
+        (6, "3,4,9,1\n"),
+        (8, "be cfbegax | fdgacbe cefdb\n"),
+        (9, "2199943210\n398789a921\n"),
+        (10, "[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{a\n"),
+        (12, "start-A\nA-b\nA-end\nAB\n"),
+        (14, "NNCB\n\nCH -> B\nHHB -> N\n"),
+        (17, "target area: x=20..30, y=-5..-10\n"),
+        (19, "--- scanner 0 ---\n404,-588,-901\n528,-643\n"),
+        (20, "..#\n\n#..#.\n"),
+        (
+            21,
+            "Player 1 starting position: 4\nPlayer 2 starting position: 11\n",
+        ),
+        (22, "on x=10..12,y=10..12,z=12..10\n"),
+        (23, "#############\n#...........#\n###B#C#E#D###\n"),
+        (24, "inp w\nadd 1 x\n"),
+        (25, "v...>>.vv>\n.vv>>.vv..\n>>.>v>.?v.\n"),
+    ];
+
+    for (day, text) in inputs {
+        let error = parse_error(day, text);
+        let line = text.lines().nth(error.location().line - 1).unwrap();
+
+        assert!(
+            !error.span().is_empty() || error.span().start == text.trim_end().len(),
+            "day {}: {}",
+            day,
+            error
+        );
+        assert!(error.snippet().contains(line), "day {}: {}", day, error);
+    }
+}
+
+#[test]
+fn deep_nesting_is_rejected() {
+    // Operator packets with a single subpacket, four of them in 18 digits.
+    let error = parse_error(16, &"020040801020040801".repeat(20));
+    assert!(
+        error.message().starts_with("Packets are nested too deeply"),
+        "{}",
+        error
+    );
+
+    let error = parse_error(18, &"[".repeat(40));
+    assert_eq!(
+        error.message(),
+        "Snailfish number is nested deeper than 32 pairs"
+    );
+    assert_eq!(
+        error.location(),
+        Location {
+            line: 1,
+            column: 33
+        }
+    );
+}
@@ -0,0 +1,637 @@
+use std::fmt::Write;
+
+use itertools::Itertools;
+use proptest::{
+    collection::{btree_set, vec},
+    prelude::*,
+    sample::{select, subsequence},
+};
+
+/// Random inputs of a day together with the values they are made of, which the
+/// reference solutions work on.
+pub trait Input {
+    fn text(&self) -> String;
+}
+
+fn lines<T>(items: &[T], line: impl Fn(&T) -> String) -> String {
+    items.iter().map(line).join("\n")
+}
+
+#[derive(Debug, Clone)]
+pub struct Depths(pub Vec<u32>);
+
+impl Input for Depths {
+    fn text(&self) -> String {
+        lines(&self.0, u32::to_string)
+    }
+}
+
+pub fn depths() -> impl Strategy<Value = Depths> {
+    vec(0..10_000u32, 1..60).prop_map(Depths)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Commands(pub Vec<Command>);
+
+impl Input for Commands {
+    fn text(&self) -> String {
+        lines(&self.0, |command| match command {
+            Command::Forward(n) => format!("forward {}", n),
+            Command::Down(n) => format!("down {}", n),
+            Command::Up(n) => format!("up {}", n),
+        })
+    }
+}
+
+pub fn commands() -> impl Strategy<Value = Commands> {
+    let command = prop_oneof![
+        (1..10i64).prop_map(Command::Forward),
+        (1..10i64).prop_map(Command::Down),
+        (1..10i64).prop_map(Command::Up),
+    ];
+
+    vec(command, 1..40).prop_map(Commands)
+}
+
+/// Distinct binary numbers of the same width.
+#[derive(Debug, Clone)]
+pub struct Diagnostics {
+    pub width: usize,
+    pub values: Vec<u32>,
+}
+
+impl Input for Diagnostics {
+    fn text(&self) -> String {
+        lines(&self.values, |v| {
+            format!("{:0width$b}", v, width = self.width)
+        })
+    }
+}
+
+pub fn diagnostics() -> impl Strategy<Value = Diagnostics> {
+    (1..=12usize).prop_flat_map(|width| {
+        let count = (1usize << width).min(40);
+        btree_set(0..1u32 << width, 1..=count)
+            .prop_map(|values| values.into_iter().collect::<Vec<_>>())
+            .prop_shuffle()
+            .prop_map(move |values| Diagnostics { width, values })
+    })
+}
+
+/// Boards with distinct numbers below 100, every number is drawn eventually.
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    pub draws: Vec<u32>,
+    pub boards: Vec<[[u32; 5]; 5]>,
+}
+
+impl Input for Bingo {
+    fn text(&self) -> String {
+        let mut text = self.draws.iter().join(",");
+        for board in &self.boards {
+            text.push('\n');
+            for row in board {
+                write!(
+                    text,
+                    "\n{}",
+                    row.iter().map(|n| format!("{:>2}", n)).join(" ")
+                )
+                .unwrap();
+            }
+        }
+        text
+    }
+}
+
+fn permutation(n: u32) -> impl Strategy<Value = Vec<u32>> {
+    Just((0..n).collect::<Vec<_>>()).prop_shuffle()
+}
+
+pub fn bingo() -> impl Strategy<Value = Bingo> {
+    let board = permutation(100).prop_map(|numbers| {
+        let mut board = [[0; 5]; 5];
+        for (i, n) in numbers.into_iter().take(25).enumerate() {
+            board[i / 5][i % 5] = n;
+        }
+        board
+    });
+
+    (permutation(100), vec(board, 1..6))
+        .prop_map(|(draws, boards)| Bingo { draws, boards })
+}
+
+/// Start and end point of a line of vents.
+pub type Vent = ((i32, i32), (i32, i32));
+
+/// Horizontal, vertical and diagonal lines.
+#[derive(Debug, Clone)]
+pub struct Vents(pub Vec<Vent>);
+
+impl Input for Vents {
+    fn text(&self) -> String {
+        lines(&self.0, |((x1, y1), (x2, y2))| {
+            format!("{},{} -> {},{}", x1, y1, x2, y2)
+        })
+    }
+}
+
+pub fn vents() -> impl Strategy<Value = Vents> {
+    let line = (0..20i32, 0..20i32, 0..20i32, 0..3, any::<bool>()).prop_filter_map(
+        "the line leaves the field",
+        |(a, b, c, kind, up)| match kind {
+            0 => Some(((a, b), (c, b))),
+            1 => Some(((a, b), (a, c))),
+            _ => {
+                let length = (c - a).abs();
+                let d = if up { b - length } else { b + length };
+                (0..20).contains(&d).then_some(((a, b), (c, d)))
+            }
+        },
+    );
+
+    vec(line, 1..30).prop_map(Vents)
+}
+
+/// Comma separated numbers, e.g. the timers of lanternfish or crab positions.
+#[derive(Debug, Clone)]
+pub struct Numbers(pub Vec<i64>);
+
+impl Input for Numbers {
+    fn text(&self) -> String {
+        self.0.iter().join(",")
+    }
+}
+
+pub fn lanternfish() -> impl Strategy<Value = Numbers> {
+    vec(0..=8i64, 1..20).prop_map(Numbers)
+}
+
+pub fn crabs() -> impl Strategy<Value = Numbers> {
+    vec(0..200i64, 1..40).prop_map(Numbers)
+}
+
+/// Segments of the digits 0 to 9, `a` to `g` are bits 0 to 6.
+pub const SEGMENTS: [u8; 10] = [
+    0b1110111, 0b0100100, 0b1011101, 0b1101101, 0b0101110, 0b1101011, 0b1111011,
+    0b0100101, 0b1111111, 0b1101111,
+];
+
+/// Seven segment displays with scrambled wires, each showing a four digit number.
+#[derive(Debug, Clone)]
+pub struct Displays(pub Vec<Display>);
+
+#[derive(Debug, Clone)]
+pub struct Display {
+    /// Wire connected to every segment.
+    pub wiring: Vec<u32>,
+    /// Order in which the ten digits are listed.
+    pub order: Vec<u32>,
+    pub output: Vec<usize>,
+}
+
+impl Display {
+    fn pattern(&self, digit: usize, sorted: bool) -> String {
+        let mut wires: Vec<_> = (0..7)
+            .filter(|&segment| SEGMENTS[digit] & 1 << segment != 0)
+            .map(|segment| (b'a' + self.wiring[segment] as u8) as char)
+            .collect();
+        if sorted {
+            wires.sort_unstable();
+        }
+        wires.into_iter().collect()
+    }
+}
+
+impl Input for Displays {
+    fn text(&self) -> String {
+        lines(&self.0, |display| {
+            let patterns = display
+                .order
+                .iter()
+                .map(|&digit| display.pattern(digit as usize, false))
+                .join(" ");
+            let output = display
+                .output
+                .iter()
+                .map(|&digit| display.pattern(digit, true))
+                .join(" ");
+            format!("{} | {}", patterns, output)
+        })
+    }
+}
+
+pub fn displays() -> impl Strategy<Value = Displays> {
+    let display = (permutation(7), permutation(10), vec(0..10usize, 4)).prop_map(
+        |(wiring, order, output)| Display {
+            wiring,
+            order,
+            output,
+        },
+    );
+
+    vec(display, 1..10).prop_map(Displays)
+}
+
+/// Rows of digits.
+#[derive(Debug, Clone)]
+pub struct DigitGrid(pub Vec<Vec<u32>>);
+
+impl Input for DigitGrid {
+    fn text(&self) -> String {
+        lines(&self.0, |row| row.iter().join(""))
+    }
+}
+
+pub fn digit_grid(digits: std::ops::Range<u32>) -> impl Strategy<Value = DigitGrid> {
+    (1..10usize, 1..10usize).prop_flat_map(move |(width, height)| {
+        vec(vec(digits.clone(), width), height).prop_map(DigitGrid)
+    })
+}
+
+/// Edges between caves, large caves are never connected to each other.
+#[derive(Debug, Clone)]
+pub struct Caves(pub Vec<(&'static str, &'static str)>);
+
+impl Input for Caves {
+    fn text(&self) -> String {
+        lines(&self.0, |(a, b)| format!("{}-{}", a, b))
+    }
+}
+
+pub fn caves() -> impl Strategy<Value = Caves> {
+    const NAMES: [&str; 8] = ["start", "end", "a", "b", "kj", "dc", "A", "HN"];
+    let is_large = |name: &str| name.chars().all(|c| c.is_ascii_uppercase());
+
+    let edges: Vec<_> = NAMES
+        .iter()
+        .tuple_combinations()
+        .filter(|&(a, b)| !is_large(a) || !is_large(b))
+        .map(|(&a, &b)| (a, b))
+        .collect();
+    let count = edges.len();
+
+    // With many edges the number of paths explodes, which only slows down the
+    // reference solutions.
+    (subsequence(edges, 1..=10), vec(any::<bool>(), count))
+        .prop_filter("start and end have to be connected", |(edges, _)| {
+            ["start", "end"]
+                .iter()
+                .all(|name| edges.iter().any(|&(a, b)| a == *name || b == *name))
+        })
+        .prop_map(|(edges, flips)| {
+            edges
+                .into_iter()
+                .zip(flips)
+                .map(|((a, b), flip)| if flip { (b, a) } else { (a, b) })
+                .collect::<Vec<_>>()
+        })
+        .prop_shuffle()
+        .prop_map(Caves)
+}
+
+/// Points on transparent paper and folds, the first one along x if `x_first`.
+///
+/// Every fold halves the paper and no point is on a fold line.
+#[derive(Debug, Clone)]
+pub struct Paper {
+    pub points: Vec<(u32, u32)>,
+    pub fold_x: u32,
+    pub fold_y: u32,
+    pub x_first: bool,
+}
+
+impl Input for Paper {
+    fn text(&self) -> String {
+        let x = format!("fold along x={}", self.fold_x);
+        let y = format!("fold along y={}", self.fold_y);
+        let folds = if self.x_first { [x, y] } else { [y, x] };
+
+        format!(
+            "{}\n\n{}",
+            lines(&self.points, |(x, y)| format!("{},{}", x, y)),
+            folds.join("\n")
+        )
+    }
+}
+
+pub fn paper() -> impl Strategy<Value = Paper> {
+    (1..15u32, 1..15u32, any::<bool>()).prop_flat_map(|(fold_x, fold_y, x_first)| {
+        let coordinate =
+            |fold: u32| (0..2 * fold).prop_map(move |c| c + (c >= fold) as u32);
+        btree_set((coordinate(fold_x), coordinate(fold_y)), 1..40).prop_map(
+            move |points| Paper {
+                points: points.into_iter().collect(),
+                fold_x,
+                fold_y,
+                x_first,
+            },
+        )
+    })
+}
+
+pub const ELEMENTS: [char; 4] = ['B', 'C', 'H', 'N'];
+
+/// A polymer template with an insertion rule for every pair of elements.
+#[derive(Debug, Clone)]
+pub struct Polymer {
+    pub template: Vec<char>,
+    /// The element inserted between `ELEMENTS[i / 4]` and `ELEMENTS[i % 4]`.
+    pub rules: Vec<char>,
+}
+
+impl Polymer {
+    pub fn rule(&self, a: char, b: char) -> char {
+        let index = |e| ELEMENTS.iter().position(|&x| x == e).unwrap();
+        self.rules[index(a) * 4 + index(b)]
+    }
+}
+
+impl Input for Polymer {
+    fn text(&self) -> String {
+        let rules = self
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(i, inserted)| {
+                format!("{}{} -> {}", ELEMENTS[i / 4], ELEMENTS[i % 4], inserted)
+            })
+            .join("\n");
+
+        format!("{}\n\n{}", self.template.iter().collect::<String>(), rules)
+    }
+}
+
+pub fn polymer() -> impl Strategy<Value = Polymer> {
+    (
+        vec(select(&ELEMENTS[..]), 2..8),
+        vec(select(&ELEMENTS[..]), 16),
+    )
+        .prop_map(|(template, rules)| Polymer { template, rules })
+}
+
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Literal {
+        version: u8,
+        value: u64,
+    },
+    Operator {
+        version: u8,
+        type_id: u8,
+        /// Whether the length is given in packets instead of bits.
+        counted: bool,
+        children: Vec<Packet>,
+    },
+}
+
+impl Packet {
+    fn write_bits(&self, bits: &mut Vec<bool>) {
+        let push = |bits: &mut Vec<bool>, value: u64, count: u32| {
+            bits.extend((0..count).rev().map(|i| value >> i & 1 == 1));
+        };
+
+        match self {
+            Packet::Literal { version, value } => {
+                push(bits, u64::from(*version), 3);
+                push(bits, 4, 3);
+
+                let groups =
+                    (0..=12).rev().find(|&g| value >> (4 * g) != 0).unwrap_or(0);
+                for group in (0..=groups).rev() {
+                    push(bits, (group > 0) as u64, 1);
+                    push(bits, value >> (4 * group) & 0xf, 4);
+                }
+            }
+            Packet::Operator {
+                version,
+                type_id,
+                counted,
+                children,
+            } => {
+                push(bits, u64::from(*version), 3);
+                push(bits, u64::from(*type_id), 3);
+
+                let mut content = Vec::new();
+                for child in children {
+                    child.write_bits(&mut content);
+                }
+
+                if *counted {
+                    push(bits, 1, 1);
+                    push(bits, children.len() as u64, 11);
+                } else {
+                    push(bits, 0, 1);
+                    push(bits, content.len() as u64, 15);
+                }
+                bits.extend(content);
+            }
+        }
+    }
+}
+
+impl Input for Packet {
+    fn text(&self) -> String {
+        let mut bits = Vec::new();
+        self.write_bits(&mut bits);
+        // Whole bytes, as the hex digits come in pairs.
+        bits.resize(bits.len().div_ceil(8) * 8, false);
+
+        bits.chunks(4)
+            .map(|nibble| {
+                let digit = nibble.iter().fold(0, |d, &bit| d << 1 | bit as u32);
+                char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+pub fn packet() -> impl Strategy<Value = Packet> {
+    let literal = (0..8u8, prop_oneof![0..16u64, 0..1u64 << 40])
+        .prop_map(|(version, value)| Packet::Literal { version, value });
+
+    literal.prop_recursive(3, 24, 3, |inner| {
+        let operator = |type_id: u8, children| {
+            (0..8u8, any::<bool>(), children).prop_map(
+                move |(version, counted, children)| Packet::Operator {
+                    version,
+                    type_id,
+                    counted,
+                    children,
+                },
+            )
+        };
+
+        prop_oneof![
+            (0..4u8).prop_flat_map({
+                let inner = inner.clone();
+                move |type_id| operator(type_id, vec(inner.clone(), 1..=3))
+            }),
+            (5..8u8).prop_flat_map(move |type_id| operator(
+                type_id,
+                vec(inner.clone(), 2)
+            ))
+        ]
+    })
+}
+
+/// A target area which can be hit by a shot which stops falling forward above it.
+#[derive(Debug, Clone)]
+pub struct TargetArea {
+    pub x: (i64, i64),
+    pub y: (i64, i64),
+}
+
+impl Input for TargetArea {
+    fn text(&self) -> String {
+        format!(
+            "target area: x={}..{}, y={}..{}",
+            self.x.0, self.x.1, self.y.0, self.y.1
+        )
+    }
+}
+
+pub fn target_area() -> impl Strategy<Value = TargetArea> {
+    (2..12i64, 0..5i64, 0..10i64, -25..-1i64, 0..10i64)
+        // The highest shot falls to the bottom of the area in `-2 * y_start` steps,
+        // the forward speed has to be used up by then.
+        .prop_filter(
+            "the shot does not stop above the area",
+            |&(speed, _, _, y_start, _)| speed <= -2 * y_start,
+        )
+        .prop_map(|(speed, before, after, y_start, height)| {
+            let stop = speed * (speed + 1) / 2;
+            TargetArea {
+                x: ((stop - before).max(1), stop + after),
+                y: (y_start, (y_start + height).min(-1)),
+            }
+        })
+}
+
+#[derive(Debug, Clone)]
+pub enum Snailfish {
+    Regular(u32),
+    Pair(Box<Snailfish>, Box<Snailfish>),
+}
+
+impl std::fmt::Display for Snailfish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Snailfish::Regular(n) => write!(f, "{}", n),
+            Snailfish::Pair(a, b) => write!(f, "[{},{}]", a, b),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Homework(pub Vec<Snailfish>);
+
+impl Input for Homework {
+    fn text(&self) -> String {
+        lines(&self.0, Snailfish::to_string)
+    }
+}
+
+/// Reduced snailfish numbers, with at most four nested pairs and regular numbers
+/// below 10.
+pub fn homework() -> impl Strategy<Value = Homework> {
+    let element =
+        (0..10u32)
+            .prop_map(Snailfish::Regular)
+            .prop_recursive(3, 16, 2, |inner| {
+                (inner.clone(), inner)
+                    .prop_map(|(a, b)| Snailfish::Pair(Box::new(a), Box::new(b)))
+            });
+    let number = (element.clone(), element)
+        .prop_map(|(a, b)| Snailfish::Pair(Box::new(a), Box::new(b)));
+
+    vec(number, 2..8).prop_map(Homework)
+}
+
+/// An enhancement algorithm and the lit pixels of an image.
+#[derive(Debug, Clone)]
+pub struct Trench {
+    pub algorithm: Vec<bool>,
+    pub image: Vec<Vec<bool>>,
+}
+
+impl Input for Trench {
+    fn text(&self) -> String {
+        let pixels = |row: &Vec<bool>| -> String {
+            row.iter().map(|&lit| if lit { '#' } else { '.' }).collect()
+        };
+
+        format!(
+            "{}\n\n{}",
+            pixels(&self.algorithm),
+            lines(&self.image, pixels)
+        )
+    }
+}
+
+pub fn trench() -> impl Strategy<Value = Trench> {
+    let image = (1..8usize, 1..8usize)
+        .prop_flat_map(|(width, height)| vec(vec(any::<bool>(), width), height));
+
+    (vec(any::<bool>(), 512), image)
+        .prop_map(|(algorithm, image)| Trench { algorithm, image })
+}
+
+#[derive(Debug, Clone)]
+pub struct Players(pub [u64; 2]);
+
+impl Input for Players {
+    fn text(&self) -> String {
+        format!(
+            "Player 1 starting position: {}\nPlayer 2 starting position: {}",
+            self.0[0], self.0[1]
+        )
+    }
+}
+
+pub fn players() -> impl Strategy<Value = Players> {
+    (1..=10u64, 1..=10u64).prop_map(|(a, b)| Players([a, b]))
+}
+
+#[derive(Debug, Clone)]
+pub struct Cuboid {
+    pub on: bool,
+    pub ranges: [(i32, i32); 3],
+}
+
+#[derive(Debug, Clone)]
+pub struct Reboot(pub Vec<Cuboid>);
+
+impl Input for Reboot {
+    fn text(&self) -> String {
+        lines(&self.0, |cuboid| {
+            let [x, y, z] = cuboid.ranges;
+            format!(
+                "{} x={}..{},y={}..{},z={}..{}",
+                if cuboid.on { "on" } else { "off" },
+                x.0,
+                x.1,
+                y.0,
+                y.1,
+                z.0,
+                z.1
+            )
+        })
+    }
+}
+
+/// Cuboids in a small region, so every cube can be counted.
+pub fn reboot() -> impl Strategy<Value = Reboot> {
+    let range =
+        (-12..12i32, 0..10i32).prop_map(|(start, length)| (start, start + length));
+    let cuboid = (any::<bool>(), [range.clone(), range.clone(), range])
+        .prop_map(|(on, ranges)| Cuboid { on, ranges });
+
+    vec(cuboid, 1..12).prop_map(Reboot)
+}
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+
+use aoc2021::{Answer, Day, Puzzle};
+use proptest::{prelude::*, test_runner::TestCaseError};
+
+use self::generators::Input;
+
+mod generators;
+mod reference;
+
+fn solve(
+    day: u32,
+    puzzle: Puzzle,
+    input: &impl Input,
+) -> Result<Answer, TestCaseError> {
+    let day = Day::new(day).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    aoc2021::solve(day, puzzle, &input.text())
+        .map_err(|e| TestCaseError::fail(format!("{:#}", e)))
+}
+
+proptest! {
+    #[test]
+    fn d01_counts_increases(depths in generators::depths()) {
+        prop_assert_eq!(
+            solve(1, Puzzle::First, &depths)?,
+            Answer::from(reference::increases(&depths.0, 1))
+        );
+        prop_assert_eq!(
+            solve(1, Puzzle::Second, &depths)?,
+            Answer::from(reference::increases(&depths.0, 3))
+        );
+    }
+
+    #[test]
+    fn d02_follows_commands(commands in generators::commands()) {
+        prop_assert_eq!(
+            solve(2, Puzzle::First, &commands)?,
+            Answer::from(reference::dive(&commands.0, false))
+        );
+        prop_assert_eq!(
+            solve(2, Puzzle::Second, &commands)?,
+            Answer::from(reference::dive(&commands.0, true))
+        );
+    }
+
+    #[test]
+    fn d03_filters_by_common_bits(report in generators::diagnostics()) {
+        prop_assert_eq!(
+            solve(3, Puzzle::First, &report)?,
+            Answer::from(reference::power_consumption(&report))
+        );
+        prop_assert_eq!(
+            solve(3, Puzzle::Second, &report)?,
+            Answer::from(reference::life_support(&report))
+        );
+    }
+
+    #[test]
+    fn d04_finds_the_first_and_last_winner(bingo in generators::bingo()) {
+        for (puzzle, last) in [(Puzzle::First, false), (Puzzle::Second, true)] {
+            let answer = solve(4, puzzle, &bingo)?;
+            let scores = reference::bingo_scores(&bingo, last);
+            prop_assert!(
+                scores.iter().any(|&s| Answer::from(s) == answer),
+                "{} is none of the scores {:?}",
+                answer,
+                scores
+            );
+        }
+    }
+
+    #[test]
+    fn d05_counts_overlaps(vents in generators::vents()) {
+        prop_assert_eq!(
+            solve(5, Puzzle::First, &vents)?,
+            Answer::from(reference::overlaps(&vents.0, false))
+        );
+        prop_assert_eq!(
+            solve(5, Puzzle::Second, &vents)?,
+            Answer::from(reference::overlaps(&vents.0, true))
+        );
+    }
+
+    #[test]
+    fn d06_counts_lanternfish(fish in generators::lanternfish()) {
+        let mut cache = HashMap::new();
+        let total: u64 = fish
+            .0
+            .iter()
+            .map(|&timer| reference::descendants(timer, 256, &mut cache))
+            .sum();
+
+        prop_assert_eq!(
+            solve(6, Puzzle::First, &fish)?,
+            Answer::from(reference::lanternfish(&fish.0, 80))
+        );
+        prop_assert_eq!(solve(6, Puzzle::Second, &fish)?, Answer::from(total));
+    }
+
+    #[test]
+    fn d07_minimizes_fuel(crabs in generators::crabs()) {
+        prop_assert_eq!(
+            solve(7, Puzzle::First, &crabs)?,
+            Answer::from(reference::fuel(&crabs.0, |d| d))
+        );
+        prop_assert_eq!(
+            solve(7, Puzzle::Second, &crabs)?,
+            Answer::from(reference::fuel(&crabs.0, |d| d * (d + 1) / 2))
+        );
+    }
+
+    #[test]
+    fn d08_decodes_displays(displays in generators::displays()) {
+        let easy = displays
+            .0
+            .iter()
+            .flat_map(|d| &d.output)
+            .filter(|&&digit| [1, 4, 7, 8].contains(&digit))
+            .count();
+        // The outputs are known, as the displays are generated from them.
+        let sum: usize = displays
+            .0
+            .iter()
+            .map(|d| d.output.iter().fold(0, |number, digit| number * 10 + digit))
+            .sum();
+
+        prop_assert_eq!(solve(8, Puzzle::First, &displays)?, Answer::from(easy));
+        prop_assert_eq!(solve(8, Puzzle::Second, &displays)?, Answer::from(sum));
+    }
+
+    #[test]
+    fn d09_sums_low_points(grid in generators::digit_grid(0..10)) {
+        prop_assert_eq!(
+            solve(9, Puzzle::First, &grid)?,
+            Answer::from(reference::low_points(&grid.0))
+        );
+    }
+
+    #[test]
+    fn d12_counts_paths(caves in generators::caves()) {
+        prop_assert_eq!(
+            solve(12, Puzzle::First, &caves)?,
+            Answer::from(reference::paths(&caves, false))
+        );
+        prop_assert_eq!(
+            solve(12, Puzzle::Second, &caves)?,
+            Answer::from(reference::paths(&caves, true))
+        );
+    }
+
+    #[test]
+    fn d13_folds_once(paper in generators::paper()) {
+        let (along_x, line) = if paper.x_first {
+            (true, paper.fold_x)
+        } else {
+            (false, paper.fold_y)
+        };
+
+        prop_assert_eq!(
+            solve(13, Puzzle::First, &paper)?,
+            Answer::from(reference::fold(&paper.points, along_x, line).len())
+        );
+    }
+
+    #[test]
+    fn d14_grows_polymers(polymer in generators::polymer()) {
+        prop_assert_eq!(
+            solve(14, Puzzle::First, &polymer)?,
+            Answer::from(reference::polymer(&polymer, 10))
+        );
+        prop_assert_eq!(
+            solve(14, Puzzle::Second, &polymer)?,
+            Answer::from(reference::polymer_counts(&polymer, 40))
+        );
+    }
+
+    #[test]
+    fn d15_finds_the_lowest_risk(grid in generators::digit_grid(1..10)) {
+        prop_assert_eq!(
+            solve(15, Puzzle::First, &grid)?,
+            Answer::from(reference::lowest_risk(&grid.0, 1))
+        );
+        prop_assert_eq!(
+            solve(15, Puzzle::Second, &grid)?,
+            Answer::from(reference::lowest_risk(&grid.0, 5))
+        );
+    }
+
+    #[test]
+    fn d16_decodes_packets(packet in generators::packet()) {
+        prop_assert_eq!(
+            solve(16, Puzzle::First, &packet)?,
+            Answer::from(reference::version_sum(&packet))
+        );
+
+        let value = reference::evaluate(&packet);
+        prop_assume!(value.is_some());
+        prop_assert_eq!(solve(16, Puzzle::Second, &packet)?, Answer::from(value.unwrap()));
+    }
+
+    #[test]
+    fn d17_tries_every_velocity(area in generators::target_area()) {
+        let (highest, hits) = reference::shots(&area);
+
+        prop_assert_eq!(solve(17, Puzzle::First, &area)?, Answer::from(highest));
+        prop_assert_eq!(solve(17, Puzzle::Second, &area)?, Answer::from(hits));
+    }
+
+    #[test]
+    fn d18_reduces_snailfish_numbers(homework in generators::homework()) {
+        prop_assert_eq!(
+            solve(18, Puzzle::First, &homework)?,
+            Answer::from(reference::sum_magnitude(&homework.0))
+        );
+        prop_assert_eq!(
+            solve(18, Puzzle::Second, &homework)?,
+            Answer::from(reference::largest_magnitude(&homework.0))
+        );
+    }
+
+    #[test]
+    fn d22_counts_cubes_like_a_voxel_set(reboot in generators::reboot()) {
+        let on = reference::reboot(&reboot.0);
+
+        prop_assert_eq!(solve(22, Puzzle::First, &reboot)?, Answer::from(on));
+        prop_assert_eq!(solve(22, Puzzle::Second, &reboot)?, Answer::from(on));
+    }
+}
+
+proptest! {
+    // Slow properties: enhancing 50 times grows the image by 100 pixels in each
+    // direction and Dirac Dice explores every universe.
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn d20_enhances_images(trench in generators::trench()) {
+        for (puzzle, steps) in [(Puzzle::First, 2), (Puzzle::Second, 50)] {
+            let day = Day::new(20).unwrap();
+            let answer = aoc2021::solve(day, puzzle, &trench.text());
+
+            match reference::enhance(&trench, steps) {
+                Some(lit) => prop_assert_eq!(answer.ok(), Some(Answer::from(lit))),
+                None => prop_assert!(answer.is_err(), "infinitely many pixels are lit"),
+            }
+        }
+    }
+
+    #[test]
+    fn d21_plays_dirac_dice(players in generators::players()) {
+        prop_assert_eq!(
+            solve(21, Puzzle::First, &players)?,
+            Answer::from(reference::deterministic_game(players.0))
+        );
+        prop_assert_eq!(
+            solve(21, Puzzle::Second, &players)?,
+            Answer::from(reference::dirac_game(players.0))
+        );
+    }
+}
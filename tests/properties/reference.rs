@@ -0,0 +1,670 @@
+use std::collections::{HashMap, HashSet};
+
+use super::generators::{
+    Bingo, Caves, Command, Cuboid, Diagnostics, Packet, Polymer, Snailfish,
+    TargetArea, Trench, Vent, ELEMENTS,
+};
+
+pub fn increases(depths: &[u32], window: usize) -> usize {
+    let sums: Vec<u32> = depths.windows(window).map(|w| w.iter().sum()).collect();
+    sums.windows(2).filter(|w| w[1] > w[0]).count()
+}
+
+pub fn dive(commands: &[Command], with_aim: bool) -> i64 {
+    let (mut position, mut depth, mut aim) = (0, 0, 0);
+
+    for &command in commands {
+        match command {
+            Command::Forward(n) => {
+                position += n;
+                depth += aim * n;
+            }
+            Command::Down(n) => aim += n,
+            Command::Up(n) => aim -= n,
+        }
+    }
+
+    if with_aim {
+        position * depth
+    } else {
+        position * aim
+    }
+}
+
+fn bit(value: u32, index: usize) -> bool {
+    value >> index & 1 == 1
+}
+
+pub fn power_consumption(report: &Diagnostics) -> u64 {
+    let (mut gamma, mut epsilon) = (0, 0);
+
+    for index in (0..report.width).rev() {
+        let ones = report.values.iter().filter(|&&v| bit(v, index)).count();
+        let most_common = 2 * ones > report.values.len();
+
+        gamma = gamma << 1 | most_common as u64;
+        epsilon = epsilon << 1 | !most_common as u64;
+    }
+
+    gamma * epsilon
+}
+
+/// Keeps the values with the most common bit, or the least common one, until a
+/// single value is left. Ties keep the ones for the most common and the zeros for
+/// the least common bit.
+fn rating(report: &Diagnostics, most_common: bool) -> u64 {
+    let mut values = report.values.clone();
+
+    for index in (0..report.width).rev() {
+        if values.len() == 1 {
+            break;
+        }
+
+        let ones = values.iter().filter(|&&v| bit(v, index)).count();
+        let zeros = values.len() - ones;
+        let keep = match (most_common, ones, zeros) {
+            (_, _, 0) => true,
+            (_, 0, _) => false,
+            (true, ones, zeros) => ones >= zeros,
+            (false, ones, zeros) => ones < zeros,
+        };
+
+        values.retain(|&v| bit(v, index) == keep);
+    }
+
+    u64::from(values[0])
+}
+
+pub fn life_support(report: &Diagnostics) -> u64 {
+    rating(report, true) * rating(report, false)
+}
+
+/// Scores of the boards which win first or last, several boards can win with the
+/// same number.
+pub fn bingo_scores(bingo: &Bingo, last: bool) -> Vec<u64> {
+    let won_after = |board: &[[u32; 5]; 5]| {
+        let drawn = |n: &u32| bingo.draws.iter().position(|d| d == n).unwrap();
+        let rows =
+            (0..5).map(|y| (0..5).map(|x| drawn(&board[y][x])).max().unwrap());
+        let columns =
+            (0..5).map(|x| (0..5).map(|y| drawn(&board[y][x])).max().unwrap());
+        rows.chain(columns).min().unwrap()
+    };
+
+    let wins: Vec<_> = bingo.boards.iter().map(won_after).collect();
+    let round = if last {
+        *wins.iter().max().unwrap()
+    } else {
+        *wins.iter().min().unwrap()
+    };
+
+    let marked = &bingo.draws[..=round];
+    bingo
+        .boards
+        .iter()
+        .zip(&wins)
+        .filter(|&(_, &won)| won == round)
+        .map(|(board, _)| {
+            let unmarked: u32 =
+                board.iter().flatten().filter(|n| !marked.contains(n)).sum();
+            u64::from(unmarked) * u64::from(bingo.draws[round])
+        })
+        .collect()
+}
+
+pub fn overlaps(lines: &[Vent], diagonals: bool) -> usize {
+    let mut counts: HashMap<(i32, i32), usize> = HashMap::new();
+
+    for &((x1, y1), (x2, y2)) in lines {
+        if !diagonals && x1 != x2 && y1 != y2 {
+            continue;
+        }
+
+        let length = (x2 - x1).abs().max((y2 - y1).abs());
+        let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+        for i in 0..=length {
+            *counts.entry((x1 + i * dx, y1 + i * dy)).or_default() += 1;
+        }
+    }
+
+    counts.values().filter(|&&count| count >= 2).count()
+}
+
+/// Simulates every single fish.
+pub fn lanternfish(timers: &[i64], days: usize) -> usize {
+    let mut fish = timers.to_vec();
+
+    for _ in 0..days {
+        let born = fish.iter().filter(|&&t| t == 0).count();
+        for timer in &mut fish {
+            *timer = if *timer == 0 { 6 } else { *timer - 1 };
+        }
+        fish.extend(std::iter::repeat_n(8, born));
+    }
+
+    fish.len()
+}
+
+/// Fish descending from a single fish with `timer` after `days`.
+pub fn descendants(
+    timer: i64,
+    days: i64,
+    cache: &mut HashMap<(i64, i64), u64>,
+) -> u64 {
+    if days <= timer {
+        return 1;
+    }
+    if let Some(&count) = cache.get(&(timer, days)) {
+        return count;
+    }
+
+    let rest = days - timer - 1;
+    let count = descendants(6, rest, cache) + descendants(8, rest, cache);
+    cache.insert((timer, days), count);
+    count
+}
+
+pub fn fuel(positions: &[i64], cost: impl Fn(i64) -> i64) -> i64 {
+    let (min, max) = (
+        *positions.iter().min().unwrap(),
+        *positions.iter().max().unwrap(),
+    );
+
+    (min..=max)
+        .map(|target| positions.iter().map(|&p| cost((p - target).abs())).sum())
+        .min()
+        .unwrap()
+}
+
+pub fn low_points(grid: &[Vec<u32>]) -> u32 {
+    let height = |x: i64, y: i64| -> Option<u32> {
+        grid.get(usize::try_from(y).ok()?)?
+            .get(usize::try_from(x).ok()?)
+            .copied()
+    };
+
+    let mut risk = 0;
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &h) in row.iter().enumerate() {
+            let (x, y) = (x as i64, y as i64);
+            let neighbors = [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)];
+            if neighbors
+                .iter()
+                .filter_map(|&(x, y)| height(x, y))
+                .all(|n| n > h)
+            {
+                risk += h + 1;
+            }
+        }
+    }
+
+    risk
+}
+
+pub fn paths(caves: &Caves, revisit: bool) -> u64 {
+    fn walk<'a>(
+        cave: &'a str,
+        edges: &HashMap<&'a str, Vec<&'a str>>,
+        path: &mut Vec<&'a str>,
+        revisit: bool,
+    ) -> u64 {
+        if cave == "end" {
+            return 1;
+        }
+
+        let mut count = 0;
+        for &next in &edges[cave] {
+            let small = next.chars().all(|c| c.is_ascii_lowercase());
+            let visited = path.contains(&next);
+            let twice = small && visited;
+
+            if next == "start" || (twice && !revisit) {
+                continue;
+            }
+
+            path.push(next);
+            count += walk(next, edges, path, revisit && !twice);
+            path.pop();
+        }
+
+        count
+    }
+
+    let mut edges: HashMap<&str, Vec<&str>> = HashMap::new();
+    for &(a, b) in &caves.0 {
+        edges.entry(a).or_default().push(b);
+        edges.entry(b).or_default().push(a);
+    }
+
+    walk("start", &edges, &mut vec!["start"], revisit)
+}
+
+pub fn fold(points: &[(u32, u32)], along_x: bool, line: u32) -> HashSet<(u32, u32)> {
+    let mirror = |c: u32| if c > line { 2 * line - c } else { c };
+
+    points
+        .iter()
+        .map(|&(x, y)| {
+            if along_x {
+                (mirror(x), y)
+            } else {
+                (x, mirror(y))
+            }
+        })
+        .collect()
+}
+
+fn element_range(counts: impl IntoIterator<Item = u64>) -> u64 {
+    let counts: Vec<_> = counts.into_iter().filter(|&c| c > 0).collect();
+    counts.iter().max().unwrap() - counts.iter().min().unwrap()
+}
+
+/// Grows the polymer element by element.
+pub fn polymer(polymer: &Polymer, steps: usize) -> u64 {
+    let mut chain = polymer.template.clone();
+
+    for _ in 0..steps {
+        let mut next = vec![chain[0]];
+        for pair in chain.windows(2) {
+            next.push(polymer.rule(pair[0], pair[1]));
+            next.push(pair[1]);
+        }
+        chain = next;
+    }
+
+    element_range(
+        ELEMENTS
+            .iter()
+            .map(|e| chain.iter().filter(|&c| c == e).count() as u64),
+    )
+}
+
+/// Counts the elements inserted between every pair recursively.
+pub fn polymer_counts(polymer: &Polymer, steps: usize) -> u64 {
+    type Cache = HashMap<(char, char, usize), [u64; 4]>;
+
+    fn inserted(
+        polymer: &Polymer,
+        a: char,
+        b: char,
+        steps: usize,
+        cache: &mut Cache,
+    ) -> [u64; 4] {
+        if steps == 0 {
+            return [0; 4];
+        }
+        if let Some(&counts) = cache.get(&(a, b, steps)) {
+            return counts;
+        }
+
+        let middle = polymer.rule(a, b);
+        let left = inserted(polymer, a, middle, steps - 1, cache);
+        let right = inserted(polymer, middle, b, steps - 1, cache);
+
+        let mut counts = [0; 4];
+        for i in 0..4 {
+            counts[i] = left[i] + right[i] + (ELEMENTS[i] == middle) as u64;
+        }
+        cache.insert((a, b, steps), counts);
+        counts
+    }
+
+    let mut cache = Cache::new();
+    let mut counts = [0; 4];
+    for (i, element) in ELEMENTS.iter().enumerate() {
+        counts[i] = polymer.template.iter().filter(|&c| c == element).count() as u64;
+    }
+    for pair in polymer.template.windows(2) {
+        let between = inserted(polymer, pair[0], pair[1], steps, &mut cache);
+        for i in 0..4 {
+            counts[i] += between[i];
+        }
+    }
+
+    element_range(counts)
+}
+
+/// Relaxes the risk of every position until nothing changes anymore.
+pub fn lowest_risk(grid: &[Vec<u32>], tiles: usize) -> u32 {
+    let (width, height) = (grid[0].len(), grid.len());
+    let (full_width, full_height) = (width * tiles, height * tiles);
+    let risk = |x: usize, y: usize| {
+        let base = grid[y % height][x % width] + (x / width + y / height) as u32;
+        (base - 1) % 9 + 1
+    };
+
+    let mut total = vec![vec![u32::MAX; full_width]; full_height];
+    total[0][0] = 0;
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for y in 0..full_height {
+            for x in 0..full_width {
+                let neighbors = [
+                    (x.wrapping_sub(1), y),
+                    (x + 1, y),
+                    (x, y.wrapping_sub(1)),
+                    (x, y + 1),
+                ];
+                for (nx, ny) in neighbors {
+                    if nx >= full_width
+                        || ny >= full_height
+                        || total[ny][nx] == u32::MAX
+                    {
+                        continue;
+                    }
+                    let candidate = total[ny][nx] + risk(x, y);
+                    if candidate < total[y][x] {
+                        total[y][x] = candidate;
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    total[full_height - 1][full_width - 1]
+}
+
+pub fn version_sum(packet: &Packet) -> u64 {
+    match packet {
+        Packet::Literal { version, .. } => u64::from(*version),
+        Packet::Operator {
+            version, children, ..
+        } => u64::from(*version) + children.iter().map(version_sum).sum::<u64>(),
+    }
+}
+
+/// The value of the packet, unless it overflows.
+pub fn evaluate(packet: &Packet) -> Option<u64> {
+    let (type_id, children) = match packet {
+        Packet::Literal { value, .. } => return Some(*value),
+        Packet::Operator {
+            type_id, children, ..
+        } => (type_id, children),
+    };
+
+    let values: Vec<u64> = children.iter().map(evaluate).collect::<Option<_>>()?;
+    match type_id {
+        0 => values.iter().try_fold(0u64, |a, &b| a.checked_add(b)),
+        1 => values.iter().try_fold(1u64, |a, &b| a.checked_mul(b)),
+        2 => values.iter().min().copied(),
+        3 => values.iter().max().copied(),
+        5 => Some((values[0] > values[1]) as u64),
+        6 => Some((values[0] < values[1]) as u64),
+        _ => Some((values[0] == values[1]) as u64),
+    }
+}
+
+/// Highest position and number of all velocities which hit the target, tried one
+/// after the other.
+pub fn shots(area: &TargetArea) -> (i64, usize) {
+    let mut highest = i64::MIN;
+    let mut hits = 0;
+
+    for vx in 0..=area.x.1 {
+        for vy in area.y.0..=-area.y.0 {
+            let (mut x, mut y, mut vx, mut vy) = (0, 0, vx, vy);
+            let mut top = 0;
+
+            while x <= area.x.1 && y >= area.y.0 {
+                if x >= area.x.0 && y <= area.y.1 {
+                    highest = highest.max(top);
+                    hits += 1;
+                    break;
+                }
+
+                x += vx;
+                y += vy;
+                top = top.max(y);
+                vx = (vx - 1).max(0);
+                vy -= 1;
+            }
+        }
+    }
+
+    (highest, hits)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Number(u32),
+}
+
+/// A snailfish number as a flat list of tokens, the commas are left out.
+fn tokens(number: &Snailfish) -> Vec<Token> {
+    match number {
+        Snailfish::Regular(n) => vec![Token::Number(*n)],
+        Snailfish::Pair(a, b) => {
+            let mut tokens = vec![Token::Open];
+            tokens.extend(self::tokens(a));
+            tokens.extend(self::tokens(b));
+            tokens.push(Token::Close);
+            tokens
+        }
+    }
+}
+
+fn explode(tokens: &mut Vec<Token>) -> bool {
+    let mut depth = 0;
+    let start = tokens.iter().position(|&t| {
+        match t {
+            Token::Open => depth += 1,
+            Token::Close => depth -= 1,
+            Token::Number(_) => {}
+        }
+        depth > 4
+    });
+
+    let start = match start {
+        Some(start) => start,
+        None => return false,
+    };
+    let (left, right) = match tokens[start + 1..start + 3] {
+        [Token::Number(left), Token::Number(right)] => (left, right),
+        _ => unreachable!("Pairs which explode consist of two regular numbers."),
+    };
+
+    if let Some(Token::Number(n)) = tokens[..start]
+        .iter_mut()
+        .rev()
+        .find(|t| matches!(t, Token::Number(_)))
+    {
+        *n += left;
+    }
+    if let Some(Token::Number(n)) = tokens[start + 4..]
+        .iter_mut()
+        .find(|t| matches!(t, Token::Number(_)))
+    {
+        *n += right;
+    }
+
+    tokens.splice(start..start + 4, [Token::Number(0)]);
+    true
+}
+
+fn split(tokens: &mut Vec<Token>) -> bool {
+    let index = tokens
+        .iter()
+        .position(|&t| matches!(t, Token::Number(n) if n >= 10));
+
+    match index {
+        Some(i) => {
+            let n = match tokens[i] {
+                Token::Number(n) => n,
+                _ => unreachable!(),
+            };
+            tokens.splice(
+                i..=i,
+                [
+                    Token::Open,
+                    Token::Number(n / 2),
+                    Token::Number(n.div_ceil(2)),
+                    Token::Close,
+                ],
+            );
+            true
+        }
+        None => false,
+    }
+}
+
+fn add(a: &[Token], b: &[Token]) -> Vec<Token> {
+    let mut sum = vec![Token::Open];
+    sum.extend_from_slice(a);
+    sum.extend_from_slice(b);
+    sum.push(Token::Close);
+
+    while explode(&mut sum) || split(&mut sum) {}
+    sum
+}
+
+fn magnitude(tokens: &[Token]) -> u64 {
+    fn parse(tokens: &[Token], i: &mut usize) -> u64 {
+        let token = tokens[*i];
+        *i += 1;
+        match token {
+            Token::Number(n) => u64::from(n),
+            Token::Open => {
+                let left = parse(tokens, i);
+                let right = parse(tokens, i);
+                *i += 1;
+                3 * left + 2 * right
+            }
+            Token::Close => unreachable!("Pairs are closed after their elements."),
+        }
+    }
+
+    parse(tokens, &mut 0)
+}
+
+pub fn sum_magnitude(numbers: &[Snailfish]) -> u64 {
+    let numbers: Vec<_> = numbers.iter().map(tokens).collect();
+    let sum = numbers[1..]
+        .iter()
+        .fold(numbers[0].clone(), |sum, n| add(&sum, n));
+
+    magnitude(&sum)
+}
+
+pub fn largest_magnitude(numbers: &[Snailfish]) -> u64 {
+    let numbers: Vec<_> = numbers.iter().map(tokens).collect();
+
+    (0..numbers.len())
+        .flat_map(|i| (0..numbers.len()).map(move |j| (i, j)))
+        .filter(|(i, j)| i != j)
+        .map(|(i, j)| magnitude(&add(&numbers[i], &numbers[j])))
+        .max()
+        .unwrap()
+}
+
+/// Lit pixels after enhancing the image, `None` if infinitely many are lit.
+pub fn enhance(trench: &Trench, steps: usize) -> Option<usize> {
+    let mut image = trench.image.clone();
+    let mut background = false;
+
+    for _ in 0..steps {
+        let (width, height) = (image[0].len() as i64, image.len() as i64);
+        let pixel = |x: i64, y: i64| {
+            if (0..width).contains(&x) && (0..height).contains(&y) {
+                image[y as usize][x as usize]
+            } else {
+                background
+            }
+        };
+
+        let next = (-1..=height)
+            .map(|y| {
+                (-1..=width)
+                    .map(|x| {
+                        let index = (-1..=1)
+                            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+                            .fold(0, |i, (dx, dy)| {
+                                i << 1 | pixel(x + dx, y + dy) as usize
+                            });
+                        trench.algorithm[index]
+                    })
+                    .collect()
+            })
+            .collect();
+
+        image = next;
+        background = trench.algorithm[if background { 511 } else { 0 }];
+    }
+
+    (!background).then(|| image.iter().flatten().filter(|&&lit| lit).count())
+}
+
+pub fn deterministic_game(positions: [u64; 2]) -> u64 {
+    let (mut positions, mut scores) = (positions, [0; 2]);
+    let mut rolls = 0;
+
+    for player in (0..2).cycle() {
+        let moved: u64 = (0..3).map(|i| (rolls + i) % 100 + 1).sum();
+        rolls += 3;
+
+        positions[player] = (positions[player] + moved - 1) % 10 + 1;
+        scores[player] += positions[player];
+        if scores[player] >= 1000 {
+            return rolls * scores[1 - player];
+        }
+    }
+
+    unreachable!()
+}
+
+/// Wins of the player to move and the other one, trying every roll in every
+/// universe.
+fn dirac_wins(
+    positions: [u64; 2],
+    scores: [u64; 2],
+    cache: &mut HashMap<([u64; 2], [u64; 2]), [u64; 2]>,
+) -> [u64; 2] {
+    if let Some(&wins) = cache.get(&(positions, scores)) {
+        return wins;
+    }
+
+    let mut wins = [0; 2];
+    for rolls in itertools::iproduct!(1..=3, 1..=3, 1..=3) {
+        let position = (positions[0] + rolls.0 + rolls.1 + rolls.2 - 1) % 10 + 1;
+        let score = scores[0] + position;
+
+        if score >= 21 {
+            wins[0] += 1;
+        } else {
+            let [other, own] =
+                dirac_wins([positions[1], position], [scores[1], score], cache);
+            wins[0] += own;
+            wins[1] += other;
+        }
+    }
+
+    cache.insert((positions, scores), wins);
+    wins
+}
+
+pub fn dirac_game(positions: [u64; 2]) -> u64 {
+    let wins = dirac_wins(positions, [0, 0], &mut HashMap::new());
+    wins[0].max(wins[1])
+}
+
+/// Switches every single cube.
+pub fn reboot(cuboids: &[Cuboid]) -> usize {
+    let mut on = HashSet::new();
+
+    for cuboid in cuboids {
+        let [x, y, z] = cuboid.ranges;
+        for cube in itertools::iproduct!(x.0..=x.1, y.0..=y.1, z.0..=z.1) {
+            if cuboid.on {
+                on.insert(cube);
+            } else {
+                on.remove(&cube);
+            }
+        }
+    }
+
+    on.len()
+}
@@ -0,0 +1,135 @@
+use std::fs;
+
+use aoc2021::{
+    raster::{ExportConfig, Image, ImageDirectory, ImageFormat, Palette, Rgb},
+    visualize::{Cell, Frame},
+    Day, Puzzle,
+};
+use owo_colors::AnsiColors;
+
+#[test]
+fn palette_maps_cells_to_colors() -> anyhow::Result<()> {
+    let mut palette = Palette {
+        background: "#000000".parse()?,
+        foreground: "#ffffff".parse()?,
+        ..Palette::default()
+    };
+    palette.symbols.insert('#', Rgb(1, 2, 3));
+    palette.colors.insert("cyan".to_string(), Rgb(0, 200, 200));
+
+    assert_eq!(palette.color(&Cell::plain('#')), Rgb(1, 2, 3));
+    assert_eq!(palette.color(&Cell::plain('.')), Rgb(0, 0, 0));
+    assert_eq!(palette.color(&Cell::plain('>')), Rgb(255, 255, 255));
+    assert_eq!(
+        palette.color(&Cell::colored('>', AnsiColors::Cyan)),
+        Rgb(0, 200, 200)
+    );
+
+    // Digits are shaded, unless they are bold.
+    assert_eq!(palette.color(&Cell::plain('4')), Rgb(128, 128, 128));
+    assert_eq!(palette.color(&Cell::plain('9')), Rgb(255, 255, 255));
+    assert_eq!(palette.color(&Cell::plain('0').bold()), Rgb(255, 255, 255));
+
+    Ok(())
+}
+
+#[test]
+fn palettes_are_loaded_from_toml() -> anyhow::Result<()> {
+    let path = std::env::temp_dir()
+        .join(format!("aoc2021-palette-{}.toml", std::process::id()));
+    fs::write(
+        &path,
+        "background = \"#102030\"\n[symbols]\n\"#\" = \"#ffff66\"\n",
+    )?;
+
+    let palette = Palette::load(&path)?;
+    fs::remove_file(&path)?;
+
+    assert_eq!(palette.background, Rgb(0x10, 0x20, 0x30));
+    assert_eq!(palette.foreground, Palette::default().foreground);
+    assert_eq!(palette.symbols[&'#'], Rgb(0xff, 0xff, 0x66));
+
+    assert!("#12345".parse::<Rgb>().is_err());
+    assert!("#12345g".parse::<Rgb>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn frames_are_scaled() -> anyhow::Result<()> {
+    let frame = Frame::Grid(vec![
+        vec![Cell::plain('#'), Cell::plain('.')],
+        vec![Cell::plain('.'), Cell::plain('#')],
+    ]);
+    let palette = Palette::default();
+
+    let image = palette.rasterize(&frame, 3)?;
+    assert_eq!((image.width, image.height), (6, 6));
+    assert_eq!(image.pixels[0], palette.foreground);
+    assert_eq!(image.pixels[3], palette.background);
+    assert_eq!(image.pixels[6 * 5 + 5], palette.foreground);
+
+    let png = image.to_png()?;
+    let mut decoder = png::Decoder::new(png.as_slice()).read_info()?;
+    let mut pixels = vec![0; decoder.output_buffer_size()];
+    let info = decoder.next_frame(&mut pixels)?;
+    assert_eq!((info.width, info.height), (6, 6));
+    assert_eq!(info.color_type, png::ColorType::Rgb);
+    assert_eq!(pixels, rgb_bytes(&image));
+
+    assert!(palette
+        .rasterize(&Frame::Dot("graph {}".into()), 1)
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn animations_are_written_when_finished() -> anyhow::Result<()> {
+    let dir =
+        std::env::temp_dir().join(format!("aoc2021-gif-{}", std::process::id()));
+    let text = fs::read_to_string("res/d25_small.txt")?;
+
+    let mut canvas =
+        ImageDirectory::create(&dir, ImageFormat::Gif, ExportConfig::default())?;
+    aoc2021::visualize(Day::new(25)?, Puzzle::First, &text, &mut canvas)?;
+
+    let gif = fs::read(dir.join("animation.gif"))?;
+    fs::remove_dir_all(&dir)?;
+
+    let mut frames: Vec<Frame> = Vec::new();
+    aoc2021::visualize(Day::new(25)?, Puzzle::First, &text, &mut frames)?;
+    let config = ExportConfig::default();
+
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(gif.as_slice())?;
+
+    for frame in &frames {
+        let image = config.palette.rasterize(frame, config.scale)?;
+        let decoded = decoder.read_next_frame()?.expect("a frame of the GIF");
+
+        assert_eq!(
+            (decoded.width as usize, decoded.height as usize),
+            (image.width, image.height)
+        );
+        let pixels: Vec<u8> = decoded
+            .buffer
+            .chunks(4)
+            .flat_map(|rgba| &rgba[..3])
+            .copied()
+            .collect();
+        assert_eq!(pixels, rgb_bytes(&image));
+    }
+    assert!(decoder.read_next_frame()?.is_none());
+
+    Ok(())
+}
+
+fn rgb_bytes(image: &Image) -> Vec<u8> {
+    image
+        .pixels
+        .iter()
+        .flat_map(|pixel| [pixel.0, pixel.1, pixel.2])
+        .collect()
+}
@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use aoc2021::{
+    inputs::Inputs,
+    record::{sha256, Record},
+    runner::run_days,
+    Answer, Day, Params, Puzzle,
+};
+
+#[test]
+fn records_have_the_answer_and_the_input() -> anyhow::Result<()> {
+    let path = Path::new("res/d01_small.txt");
+    let text = std::fs::read_to_string(path)?;
+    let record = Record::solve(
+        Day::new(1)?,
+        Puzzle::Second,
+        Some(path),
+        &text,
+        &Params::default(),
+    );
+
+    assert_eq!(record.input.as_deref(), Some(path));
+    assert_eq!(record.input_sha256, Some(sha256(&text)));
+    assert_eq!(record.answer, Some(Answer::Integer(5)));
+    assert_eq!(record.error, None);
+    assert!(record.parse_ns.unwrap() <= record.time_ns);
+
+    let mut json = Vec::new();
+    record.write(&mut json)?;
+    assert_eq!(json.iter().filter(|&&b| b == b'\n').count(), 1);
+    assert_eq!(serde_json::from_slice::<Record>(&json)?, record);
+
+    Ok(())
+}
+
+#[test]
+fn records_have_the_error() -> anyhow::Result<()> {
+    let record = Record::solve(
+        Day::new(6)?,
+        Puzzle::First,
+        None,
+        "1,x\n",
+        &Params::default(),
+    );
+
+    assert_eq!(record.answer, None);
+    assert!(record.error.unwrap().starts_with("Invalid number 'x'"));
+
+    let reports = run_days(&"1".parse()?, &Inputs::default(), "missing");
+    let path = Inputs::default().path(Day::new(1)?, "missing");
+    let record = Record::from_report(&reports[0], &path);
+
+    assert_eq!(record.input_sha256, None);
+    assert!(record.error.unwrap().starts_with("Can not read"));
+
+    Ok(())
+}
+
+#[test]
+fn hashes_are_hex_encoded_sha256() {
+    assert_eq!(
+        sha256("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
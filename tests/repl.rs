@@ -0,0 +1,55 @@
+use aoc2021::{inspect, Day};
+
+fn repl(day: u32, commands: &str) -> anyhow::Result<String> {
+    let text = std::fs::read_to_string(format!("res/d{:02}_small.txt", day))?;
+    let mut out = Vec::new();
+    inspect(Day::new(day)?, &text, commands.as_bytes(), &mut out, false)?;
+
+    Ok(String::from_utf8(out)?)
+}
+
+#[test]
+fn caves_can_be_queried() -> anyhow::Result<()> {
+    let out = repl(12, "query cave start\nquery paths\nquery paths twice\n")?;
+    assert_eq!(out, "start (small): A, b\n10\n36\n");
+
+    Ok(())
+}
+
+#[test]
+fn polymer_steps_and_resets() -> anyhow::Result<()> {
+    let out = repl(
+        14,
+        "step 2\nquery pair NB\nstep 8\nquery score\nreset\nshow\nquit\nshow\n",
+    )?;
+    assert_eq!(out, "2\n1588\nStep 0, 4 elements\nCB: 1\nNC: 1\nNN: 1\n");
+
+    Ok(())
+}
+
+#[test]
+fn scanners_are_aligned_step_by_step() -> anyhow::Result<()> {
+    let out = repl(19, "query beacons\nstep 4\nquery beacons\n")?;
+    assert_eq!(out, "25\n79\n");
+
+    Ok(())
+}
+
+#[test]
+fn failed_commands_are_reported() -> anyhow::Result<()> {
+    let out = repl(12, "step\nfly\nquery nope\nquery cave X\n")?;
+    assert_eq!(
+        out.lines().collect::<Vec<_>>(),
+        [
+            "error: There are no steps to take.",
+            "error: Unknown command 'fly', see `help`.",
+            "error: Day 12 has no query 'nope', see `help`.",
+            "error: There is no cave 'X'.",
+        ]
+    );
+
+    let error = repl(1, "show\n").unwrap_err();
+    assert_eq!(error.to_string(), "Day 1 can not be inspected.");
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use std::num::NonZeroUsize;
+
+use aoc2021::{
+    inputs::Inputs,
+    runner::{run_days, run_days_parallel, DaySelection, Report, RunConfig},
+    Answer, Day, Puzzle,
+};
+
+#[test]
+fn day_selection_with_ranges() -> anyhow::Result<()> {
+    let selection: DaySelection = "3-5, 1,4".parse()?;
+
+    let expected: Vec<_> = [1, 3, 4, 5]
+        .into_iter()
+        .map(Day::new)
+        .collect::<Result<_, _>>()?;
+    assert_eq!(selection.days(), expected);
+
+    assert!("5-3".parse::<DaySelection>().is_err());
+    assert!("1,26".parse::<DaySelection>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn failures_are_reported_per_puzzle() -> anyhow::Result<()> {
+    let reports = run_days(&"1,25".parse()?, &Inputs::default(), "small");
+
+    let answers: Vec<_> = reports
+        .iter()
+        .map(|r| (r.day, r.puzzle, r.answer.as_ref().ok().cloned()))
+        .collect();
+
+    assert_eq!(
+        answers,
+        [
+            (Day::new(1)?, Puzzle::First, Some(Answer::Integer(7))),
+            (Day::new(1)?, Puzzle::Second, Some(Answer::Integer(5))),
+            (Day::new(25)?, Puzzle::First, Some(Answer::Integer(58))),
+        ]
+    );
+
+    let reports = run_days(&"1".parse()?, &Inputs::default(), "missing");
+    assert_eq!(reports.len(), 2);
+    assert!(reports.iter().all(|r| r.answer.is_err()));
+
+    Ok(())
+}
+
+#[test]
+fn parallel_reports_keep_their_order() -> anyhow::Result<()> {
+    let days: DaySelection = "1-3,12,25".parse()?;
+    let outcome = |reports: Vec<_>| -> Vec<_> {
+        reports
+            .into_iter()
+            .map(|r: Report| (r.day, r.puzzle, r.answer.map_err(|e| e.to_string())))
+            .collect()
+    };
+
+    let sequential = outcome(run_days(&days, &Inputs::default(), "small"));
+    for jobs in [1, 4] {
+        let config = RunConfig {
+            jobs: NonZeroUsize::new(jobs).unwrap(),
+            ..RunConfig::default()
+        };
+        let parallel = outcome(run_days_parallel(
+            &days,
+            &Inputs::default(),
+            "small",
+            &config,
+        ));
+        assert_eq!(parallel, sequential);
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(not(all(unix, feature = "cli")))]
+fn limits_are_unsupported_without_workers() {
+    use aoc2021::runner::{LimitError, Limits};
+
+    let config = RunConfig {
+        limits: Some(Limits {
+            program: "aoc2021".into(),
+            timeout: None,
+            memory: Some(1 << 30),
+        }),
+        ..RunConfig::default()
+    };
+    let reports = run_days_parallel(
+        &"1".parse().unwrap(),
+        &Inputs::default(),
+        "small",
+        &config,
+    );
+
+    assert!(reports.iter().all(|r| matches!(
+        r.answer.as_ref().err().and_then(|e| e.downcast_ref()),
+        Some(LimitError::Unsupported)
+    )));
+}
@@ -0,0 +1,63 @@
+use std::fs;
+
+use aoc2021::{simulation::Player, solver, Day, Puzzle};
+
+#[test]
+fn player_steps_back_and_forth() -> anyhow::Result<()> {
+    let day11 = solver(Day::new(11)?).unwrap();
+    let parsed = day11.parse(&fs::read_to_string("res/d11_small.txt")?)?;
+    let mut player = Player::new(day11.simulation(&parsed, Puzzle::First)?);
+
+    let initial = player.frame().clone();
+    assert!(!player.back());
+
+    assert!(player.forward());
+    assert_eq!(player.step(), 1);
+    assert_ne!(player.frame(), &initial);
+
+    assert!(player.back());
+    assert_eq!(player.frame(), &initial);
+
+    player.jump(42);
+    assert_eq!(player.step(), 42);
+    assert_eq!(player.last_step(), None);
+
+    // The first puzzle of day 11 ends after step 100.
+    player.jump(1000);
+    assert_eq!(player.step(), 100);
+    assert_eq!(player.last_step(), Some(100));
+    assert!(!player.forward());
+
+    Ok(())
+}
+
+#[test]
+fn simulations_match_the_visualization() -> anyhow::Result<()> {
+    let text = fs::read_to_string("res/d25_small.txt")?;
+
+    let mut frames = Vec::new();
+    aoc2021::visualize(Day::new(25)?, Puzzle::First, &text, &mut frames)?;
+
+    let day25 = solver(Day::new(25)?).unwrap();
+    let parsed = day25.parse(&text)?;
+    let mut player = Player::new(day25.simulation(&parsed, Puzzle::First)?);
+
+    for frame in &frames {
+        assert_eq!(player.frame(), frame);
+        player.forward();
+    }
+    assert_eq!(player.last_step(), Some(frames.len() - 1));
+
+    Ok(())
+}
+
+#[test]
+fn days_without_simulation_are_an_error() -> anyhow::Result<()> {
+    let day01 = solver(Day::new(1)?).unwrap();
+    let parsed = day01.parse(&fs::read_to_string("res/d01_small.txt")?)?;
+
+    let err = day01.simulation(&parsed, Puzzle::First).err().unwrap();
+    assert_eq!(err.to_string(), "Day 1 has no simulation.");
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use aoc2021::{solver, solvers, Answer, Day, Puzzle};
+
+#[test]
+fn every_day_is_registered_in_order() {
+    let days: Vec<_> = solvers().map(|s| s.day()).collect();
+    let expected: Vec<_> = (1..=25).map(|d| Day::new(d).unwrap()).collect();
+
+    assert_eq!(days, expected);
+}
+
+#[test]
+fn missing_puzzles_are_reported() -> anyhow::Result<()> {
+    let day25 = solver(Day::new(25)?).unwrap();
+
+    assert_eq!(day25.puzzles(), &[Puzzle::First]);
+
+    let parsed = day25.parse(&std::fs::read_to_string("res/d25_small.txt")?)?;
+    let err = day25.solve(&parsed, Puzzle::Second).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Day 25 has no Second puzzle, available: First."
+    );
+
+    Ok(())
+}
+
+#[test]
+fn solve_inline_input() -> anyhow::Result<()> {
+    let text = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    assert_eq!(
+        aoc2021::solve(Day::new(1)?, Puzzle::First, text)?,
+        Answer::Integer(7)
+    );
+    assert_eq!(
+        aoc2021::solve(Day::new(1)?, Puzzle::Second, text)?,
+        Answer::Integer(5)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn structured_answers() -> anyhow::Result<()> {
+    let text = std::fs::read_to_string("res/d13_small.txt")?;
+    let answer = aoc2021::solve(Day::new(13)?, Puzzle::Second, &text)?;
+
+    let Answer::Grid(rows) = &answer else {
+        panic!("Expected a grid, got {:?}", answer);
+    };
+    assert_eq!(rows.len(), 5);
+    assert_eq!(rows[0], "#####");
+
+    let json = serde_json::to_string(&Answer::from(26984457539usize))?;
+    assert_eq!(json, r#"{"type":"integer","value":26984457539}"#);
+    assert_eq!(
+        serde_json::from_str::<Answer>(&json)?,
+        Answer::Integer(26984457539)
+    );
+
+    let big = Answer::from(u64::MAX);
+    assert_eq!(big, Answer::BigInteger(u64::MAX.into()));
+    let json = serde_json::to_string(&big)?;
+    assert_eq!(
+        json,
+        r#"{"type":"big_integer","value":"18446744073709551615"}"#
+    );
+    assert_eq!(serde_json::from_str::<Answer>(&json)?, big);
+
+    Ok(())
+}
+
+#[test]
+fn overlapping_cuboids_are_counted_once() -> anyhow::Result<()> {
+    let solve =
+        |puzzle, text: &str| aoc2021::solve(Day::new(22).unwrap(), puzzle, text);
+
+    let identical = "on x=0..1,y=0..1,z=0..1\n".repeat(26);
+    assert_eq!(solve(Puzzle::First, &identical)?, Answer::Integer(8));
+    assert_eq!(solve(Puzzle::Second, &identical)?, Answer::Integer(8));
+
+    let shifted: String = (0..40)
+        .map(|x| format!("on x={}..{},y=0..10,z=0..10\n", x, x + 10))
+        .collect();
+    assert_eq!(
+        solve(Puzzle::Second, &shifted)?,
+        Answer::Integer(50 * 11 * 11)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_input_is_an_error() {
+    let solve = |day, puzzle, text| {
+        aoc2021::solve(Day::new(day).unwrap(), puzzle, text)
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert!(solve(1, Puzzle::First, "199\n2x0\n")
+        .starts_with("Invalid number '2x0' at line 2, column 1."));
+    assert!(solve(16, Puzzle::First, "8A00G").contains("line 1, column 5"));
+    assert!(solve(19, Puzzle::First, "--- scanner 0 ---\n1,2\n")
+        .contains("line 2, column 1"));
+    assert!(solve(25, Puzzle::Second, "v.\n").contains("no Second puzzle"));
+    assert!(solve(
+        13,
+        Puzzle::Second,
+        "2000000000,2000000000\n\nfold along y=2100000000\n"
+    )
+    .contains("too large"));
+    assert!(solve(24, Puzzle::First, "inp w\n").contains("instead of the 252"));
+    assert!(solve(5, Puzzle::First, "0,0 -> 2147483647,2147483647\n")
+        .contains("too large"));
+
+    for day in 1..=25 {
+        for puzzle in [Puzzle::First, Puzzle::Second] {
+            let _ = aoc2021::solve(Day::new(day).unwrap(), puzzle, "");
+            let _ = aoc2021::solve(Day::new(day).unwrap(), puzzle, "#\n1 2\n\n");
+        }
+    }
+
+    assert!(Day::new(26).is_err());
+}
+
+#[test]
+fn typed_solvers_are_public() -> anyhow::Result<()> {
+    use aoc2021::{solvers::Day11, Grid, Solution};
+
+    let grid: Grid<u32> =
+        Day11::parse(&std::fs::read_to_string("res/d11_small.txt")?)?;
+
+    assert_eq!(Day11::part1(&grid)?, 1656);
+    assert_eq!(Day11::part2(&grid)?, 195);
+
+    Ok(())
+}
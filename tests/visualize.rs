@@ -0,0 +1,72 @@
+use std::fs;
+
+use aoc2021::{
+    visualize::{Canvas, Directory, Frame},
+    Day, Puzzle,
+};
+
+fn frames(day: u32, puzzle: Puzzle, file: &str) -> anyhow::Result<Vec<Frame>> {
+    let text = fs::read_to_string(file)?;
+    let mut frames = Vec::new();
+    aoc2021::visualize(Day::new(day)?, puzzle, &text, &mut frames)?;
+
+    Ok(frames)
+}
+
+#[test]
+fn every_step_is_a_frame() -> anyhow::Result<()> {
+    let frames = frames(11, Puzzle::First, "res/d11_small.txt")?;
+
+    // The initial grid and one frame for each of the 100 steps.
+    assert_eq!(frames.len(), 101);
+    assert_eq!(frames[0].to_plain_text().lines().next(), Some("5483143223"));
+
+    let frames = self::frames(11, Puzzle::Second, "res/d11_small.txt")?;
+    let last = frames.last().unwrap().to_plain_text();
+    assert!(last.lines().all(|line| line == "0000000000"));
+
+    Ok(())
+}
+
+#[test]
+fn graphs_are_dot_frames() -> anyhow::Result<()> {
+    let frames = frames(12, Puzzle::First, "res/d12_small.txt")?;
+
+    assert_eq!(frames.len(), 1);
+    match &frames[0] {
+        Frame::Dot(dot) => assert!(dot.starts_with("graph {")),
+        frame => panic!("Expected a dot frame, got {:?}", frame),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn days_without_visualization_are_an_error() -> anyhow::Result<()> {
+    let err = frames(1, Puzzle::First, "res/d01_small.txt").unwrap_err();
+
+    assert_eq!(err.to_string(), "Day 1 has no visualization.");
+
+    Ok(())
+}
+
+#[test]
+fn frames_are_written_to_numbered_files() -> anyhow::Result<()> {
+    let dir =
+        std::env::temp_dir().join(format!("aoc2021-frames-{}", std::process::id()));
+    let frames = frames(25, Puzzle::First, "res/d25_small.txt")?;
+
+    let mut canvas = Directory::create(&dir)?;
+    for frame in frames.iter().cloned() {
+        canvas.draw(frame)?;
+    }
+
+    assert_eq!(fs::read_dir(&dir)?.count(), frames.len());
+    assert_eq!(
+        fs::read_to_string(dir.join("frame_0000.txt"))?,
+        frames[0].to_plain_text()
+    );
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use std::{fs, path::PathBuf};
+
+use aoc2021::{watch::Watch, Answer, Day, Params, Puzzle};
+
+fn watch(input: &str, answers: Option<PathBuf>) -> Watch {
+    Watch {
+        day: Day::new(1).unwrap(),
+        puzzle: Puzzle::First,
+        input: input.into(),
+        answers,
+        params: Params::default(),
+    }
+}
+
+#[test]
+fn answers_are_checked_against_the_manifest() -> anyhow::Result<()> {
+    let watch = watch("res/d01_small.txt", Some("res/answers.toml".into()));
+    let report = watch.run_once();
+
+    assert_eq!(report.passed(), Some(true));
+
+    let mut out = Vec::new();
+    watch.print_report(&report, &mut out)?;
+    assert_eq!(report.answer?, Answer::Integer(7));
+
+    let out = String::from_utf8(out)?;
+    assert!(out.starts_with("Day 1, First puzzle, res/d01_small.txt\n\n7\n"));
+    assert!(out.contains("PASS"));
+
+    Ok(())
+}
+
+#[test]
+fn wrong_answers_are_reported() -> anyhow::Result<()> {
+    let path = std::env::temp_dir()
+        .join(format!("aoc2021-watch-answers-{}.toml", std::process::id()));
+    fs::write(&path, "[d01.small]\nfirst = 8\n")?;
+
+    let watch = watch("res/d01_small.txt", Some(path.clone()));
+    let report = watch.run_once();
+    fs::remove_file(&path)?;
+
+    assert_eq!(report.passed(), Some(false));
+
+    let mut out = Vec::new();
+    watch.print_report(&report, &mut out)?;
+    let out = String::from_utf8(out)?;
+    assert!(out.contains("FAIL"));
+    assert!(out.contains("- 8"));
+    assert!(out.contains("+ 7"));
+
+    Ok(())
+}
+
+#[test]
+fn missing_inputs_are_shown_as_errors() -> anyhow::Result<()> {
+    let report = watch("res/d01_missing.txt", None).run_once();
+
+    assert!(report.answer.is_err());
+    assert!(matches!(report.expected, Ok(None)));
+    assert_eq!(report.passed(), None);
+
+    Ok(())
+}